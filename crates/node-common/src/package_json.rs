@@ -9,3 +9,38 @@ pub struct VoltaField {
     // Special
     pub extends: Option<String>,
 }
+
+// Parses the `packageManager` field into a name and version.
+// The version may include a corepack hash: pnpm@9.1.0+sha512.abc
+pub fn parse_package_manager_field(value: &str) -> (&str, Option<&str>) {
+    match value.split_once('@') {
+        Some((name, version)) => (
+            name,
+            Some(
+                version
+                    .split_once('+')
+                    .map(|(version, _)| version)
+                    .unwrap_or(version),
+            ),
+        ),
+        None => (value, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_package_manager() {
+        assert_eq!(parse_package_manager_field("bun"), ("bun", None));
+        assert_eq!(
+            parse_package_manager_field("bun@1.1.20"),
+            ("bun", Some("1.1.20"))
+        );
+        assert_eq!(
+            parse_package_manager_field("pnpm@9.1.0+sha512.abc"),
+            ("pnpm", Some("9.1.0"))
+        );
+    }
+}
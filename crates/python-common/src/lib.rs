@@ -0,0 +1,7 @@
+mod pep440;
+mod pyproject_toml;
+mod uv_toml;
+
+pub use pep440::*;
+pub use pyproject_toml::*;
+pub use uv_toml::*;
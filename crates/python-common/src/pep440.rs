@@ -0,0 +1,127 @@
+fn from_pep440_clause(clause: &str) -> Option<String> {
+    let clause = clause.trim();
+
+    if clause.is_empty() {
+        return None;
+    }
+
+    // Order is important here, as some operators are prefixes of others
+    for (op, replacement) in [
+        ("===", "="),
+        ("==", "="),
+        ("~=", "~="),
+        ("!=", "!="),
+        (">=", ">="),
+        ("<=", "<="),
+        (">", ">"),
+        ("<", "<"),
+        ("^", "^"),
+        ("~", "~"),
+    ] {
+        let Some(version) = clause.strip_prefix(op) else {
+            continue;
+        };
+
+        let version = version.trim();
+
+        return match replacement {
+            // Exclusions are not supported by semver requirements
+            "!=" => None,
+            // Compatible releases: ~=3.11 (>=3.11, ==3.*), ~=3.11.2 (>=3.11.2, ==3.11.*)
+            "~=" => {
+                if version.matches('.').count() >= 2 {
+                    Some(format!("~{version}"))
+                } else {
+                    Some(format!("^{version}"))
+                }
+            }
+            // Wildcard matching: ==3.11.*
+            // Exact matching is zero padded: ==3.11 (==3.11.0)
+            "=" => match version.strip_suffix(".*") {
+                Some(prefix) => Some(format!("~{prefix}")),
+                None => Some(format!(
+                    "={version}{}",
+                    ".0".repeat(2usize.saturating_sub(version.matches('.').count()))
+                )),
+            },
+            _ => Some(format!("{replacement}{version}")),
+        };
+    }
+
+    Some(clause.to_owned())
+}
+
+// Converts a PEP 440 version specifier (and Poetry's extended syntax)
+// into a semver compatible requirement that proto can parse.
+// https://peps.python.org/pep-0440/#version-specifiers
+pub fn from_pep440_specifier(specifier: &str) -> Option<String> {
+    let mut reqs = vec![];
+
+    for any in specifier.split("||") {
+        let clauses = any
+            .split(',')
+            .filter_map(from_pep440_clause)
+            .collect::<Vec<_>>();
+
+        if !clauses.is_empty() {
+            reqs.push(clauses.join(", "));
+        }
+    }
+
+    if reqs.is_empty() {
+        None
+    } else {
+        Some(reqs.join(" || "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_pep440_operators() {
+        assert_eq!(from_pep440_specifier("3.11"), Some("3.11".into()));
+        assert_eq!(from_pep440_specifier("==3.11.4"), Some("=3.11.4".into()));
+        assert_eq!(from_pep440_specifier("===3.11.4"), Some("=3.11.4".into()));
+        assert_eq!(from_pep440_specifier("==3.11"), Some("=3.11.0".into()));
+        assert_eq!(from_pep440_specifier("==3"), Some("=3.0.0".into()));
+        assert_eq!(from_pep440_specifier("==3.11.*"), Some("~3.11".into()));
+        assert_eq!(from_pep440_specifier(">=3.10"), Some(">=3.10".into()));
+        assert_eq!(from_pep440_specifier("< 3.13"), Some("<3.13".into()));
+    }
+
+    #[test]
+    fn converts_pep440_compatible_releases() {
+        assert_eq!(from_pep440_specifier("~=3.11"), Some("^3.11".into()));
+        assert_eq!(from_pep440_specifier("~=3.11.2"), Some("~3.11.2".into()));
+    }
+
+    #[test]
+    fn converts_pep440_multiple_clauses() {
+        assert_eq!(
+            from_pep440_specifier(">=3.10,<3.13"),
+            Some(">=3.10, <3.13".into())
+        );
+        assert_eq!(
+            from_pep440_specifier(">=3.10, !=3.11.0, <4"),
+            Some(">=3.10, <4".into())
+        );
+    }
+
+    #[test]
+    fn converts_poetry_syntax() {
+        assert_eq!(from_pep440_specifier("^3.10"), Some("^3.10".into()));
+        assert_eq!(from_pep440_specifier("~3.10"), Some("~3.10".into()));
+        assert_eq!(
+            from_pep440_specifier("^2.7 || ^3.8"),
+            Some("^2.7 || ^3.8".into())
+        );
+    }
+
+    #[test]
+    fn ignores_unsupported() {
+        assert_eq!(from_pep440_specifier(""), None);
+        assert_eq!(from_pep440_specifier("!=3.11.0"), None);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::uv_toml::UvToml;
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ProjectSection {
+    pub requires_python: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct PoetryDependencies {
+    pub python: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct PoetrySection {
+    pub dependencies: PoetryDependencies,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct ToolSection {
+    pub poetry: PoetrySection,
+    pub uv: UvToml,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct PyProjectToml {
+    pub project: ProjectSection,
+    pub tool: ToolSection,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+// Also used for the `[tool.uv]` section in pyproject.toml
+// https://docs.astral.sh/uv/reference/settings/#required-version
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct UvToml {
+    pub required_version: Option<String>,
+}
@@ -0,0 +1,78 @@
+use proto_pdk_api::HostOS;
+
+/// Archive format of a release asset on Unix hosts (Linux, macOS).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnixArchive {
+    TarGz,
+    TarXz,
+    Zip,
+}
+
+impl UnixArchive {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::TarGz => "tar.gz",
+            Self::TarXz => "tar.xz",
+            Self::Zip => "zip",
+        }
+    }
+}
+
+/// Return the file name of an archive for the host OS. Releases
+/// for Windows are always zip, while other platforms vary by tool.
+pub fn get_archive_file(name: &str, os: HostOS, unix: UnixArchive) -> String {
+    if os.is_windows() {
+        format!("{name}.zip")
+    } else {
+        format!("{name}.{}", unix.extension())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uses_unix_archive() {
+        assert_eq!(
+            get_archive_file(
+                "uv-x86_64-unknown-linux-gnu",
+                HostOS::Linux,
+                UnixArchive::TarGz
+            ),
+            "uv-x86_64-unknown-linux-gnu.tar.gz"
+        );
+        assert_eq!(
+            get_archive_file(
+                "proto_cli-aarch64-apple-darwin",
+                HostOS::MacOS,
+                UnixArchive::TarXz
+            ),
+            "proto_cli-aarch64-apple-darwin.tar.xz"
+        );
+        assert_eq!(
+            get_archive_file("deno-aarch64-apple-darwin", HostOS::MacOS, UnixArchive::Zip),
+            "deno-aarch64-apple-darwin.zip"
+        );
+    }
+
+    #[test]
+    fn uses_zip_on_windows() {
+        assert_eq!(
+            get_archive_file(
+                "uv-x86_64-pc-windows-msvc",
+                HostOS::Windows,
+                UnixArchive::TarGz
+            ),
+            "uv-x86_64-pc-windows-msvc.zip"
+        );
+        assert_eq!(
+            get_archive_file(
+                "proto_cli-x86_64-pc-windows-msvc",
+                HostOS::Windows,
+                UnixArchive::TarXz
+            ),
+            "proto_cli-x86_64-pc-windows-msvc.zip"
+        );
+    }
+}
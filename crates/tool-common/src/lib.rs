@@ -0,0 +1,9 @@
+mod archive;
+mod platforms;
+mod target;
+mod tool_versions;
+
+pub use archive::*;
+pub use platforms::*;
+pub use target::*;
+pub use tool_versions::*;
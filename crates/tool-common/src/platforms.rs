@@ -0,0 +1,123 @@
+use proto_pdk_api::{HostArch, HostOS};
+use rustc_hash::FxHashMap;
+
+/// A support matrix of operating systems and their architectures.
+pub struct PlatformTable(&'static [(HostOS, &'static [HostArch])]);
+
+impl PlatformTable {
+    pub fn supports(&self, os: HostOS, arch: HostArch) -> bool {
+        self.0
+            .iter()
+            .any(|(table_os, archs)| *table_os == os && archs.contains(&arch))
+    }
+
+    /// Convert to the permutations that `check_supported_os_and_arch` expects.
+    pub fn to_permutations(&self) -> FxHashMap<HostOS, Vec<HostArch>> {
+        self.0
+            .iter()
+            .map(|(os, archs)| (*os, archs.to_vec()))
+            .collect()
+    }
+}
+
+pub static BUN_PLATFORMS: PlatformTable = PlatformTable(&[
+    (HostOS::Linux, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::MacOS, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::Windows, &[HostArch::X64]),
+]);
+
+// Windows builds were first published in v1.1
+pub static BUN_LEGACY_PLATFORMS: PlatformTable = PlatformTable(&[
+    (HostOS::Linux, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::MacOS, &[HostArch::X64, HostArch::Arm64]),
+]);
+
+pub static DENO_PLATFORMS: PlatformTable = PlatformTable(&[
+    (HostOS::Linux, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::MacOS, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::Windows, &[HostArch::X64]),
+]);
+
+pub static JUST_PLATFORMS: PlatformTable = PlatformTable(&[
+    (
+        HostOS::Linux,
+        &[HostArch::X64, HostArch::Arm64, HostArch::Arm],
+    ),
+    (HostOS::MacOS, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::Windows, &[HostArch::X64, HostArch::Arm64]),
+]);
+
+pub static MOON_PLATFORMS: PlatformTable = PlatformTable(&[
+    (HostOS::Linux, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::MacOS, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::Windows, &[HostArch::X64]),
+]);
+
+pub static PROTO_PLATFORMS: PlatformTable = PlatformTable(&[
+    (HostOS::Linux, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::MacOS, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::Windows, &[HostArch::X64]),
+]);
+
+pub static UV_PLATFORMS: PlatformTable = PlatformTable(&[
+    (HostOS::Linux, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::MacOS, &[HostArch::X64, HostArch::Arm64]),
+    (HostOS::Windows, &[HostArch::X64]),
+]);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::target::{get_rust_target, TargetLibc};
+    use proto_pdk_api::HostEnvironment;
+
+    #[test]
+    fn checks_support() {
+        assert!(JUST_PLATFORMS.supports(HostOS::Linux, HostArch::Arm));
+        assert!(JUST_PLATFORMS.supports(HostOS::Windows, HostArch::Arm64));
+        assert!(!JUST_PLATFORMS.supports(HostOS::Windows, HostArch::X86));
+        assert!(!BUN_LEGACY_PLATFORMS.supports(HostOS::Windows, HostArch::X64));
+        assert!(!PROTO_PLATFORMS.supports(HostOS::FreeBSD, HostArch::X64));
+    }
+
+    #[test]
+    fn converts_to_permutations() {
+        let permutations = UV_PLATFORMS.to_permutations();
+
+        assert_eq!(permutations.len(), 3);
+        assert_eq!(
+            permutations.get(&HostOS::Linux).unwrap(),
+            &vec![HostArch::X64, HostArch::Arm64]
+        );
+        assert_eq!(
+            permutations.get(&HostOS::Windows).unwrap(),
+            &vec![HostArch::X64]
+        );
+    }
+
+    #[test]
+    fn rust_triple_tools_have_targets() {
+        for table in [
+            &DENO_PLATFORMS,
+            &JUST_PLATFORMS,
+            &MOON_PLATFORMS,
+            &PROTO_PLATFORMS,
+            &UV_PLATFORMS,
+        ] {
+            for (os, archs) in table.0 {
+                for arch in *archs {
+                    let env = HostEnvironment {
+                        arch: *arch,
+                        os: *os,
+                        ..Default::default()
+                    };
+
+                    assert!(
+                        get_rust_target(&env, TargetLibc::Host).is_some(),
+                        "{os:?} {arch:?}"
+                    );
+                }
+            }
+        }
+    }
+}
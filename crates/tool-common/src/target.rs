@@ -0,0 +1,178 @@
+use proto_pdk_api::{HostArch, HostEnvironment, HostLibc, HostOS};
+
+/// Which libc a Linux target triple should be built for.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TargetLibc {
+    /// Use the libc of the host, falling back to glibc when unknown.
+    Host,
+    /// Always use glibc (dynamically linked) builds.
+    Gnu,
+    /// Always use musl (statically linked) builds.
+    Musl,
+}
+
+/// Return the Rust target triple for the host and libc, or `None`
+/// when the OS or architecture has no known triple.
+///
+/// - `x86_64-unknown-linux-gnu`
+/// - `armv7-unknown-linux-musleabihf`
+/// - `aarch64-apple-darwin`
+/// - `i686-pc-windows-msvc`
+pub fn get_rust_target(env: &HostEnvironment, libc: TargetLibc) -> Option<String> {
+    let arch = match env.arch {
+        HostArch::X86 => "i686",
+        HostArch::X64 => "x86_64",
+        HostArch::Arm => "armv7",
+        HostArch::Arm64 => "aarch64",
+        _ => return None,
+    };
+
+    let target = match env.os {
+        HostOS::Linux => {
+            let musl = match libc {
+                TargetLibc::Host => env.libc == HostLibc::Musl,
+                TargetLibc::Gnu => false,
+                TargetLibc::Musl => true,
+            };
+            let abi = match (musl, env.arch) {
+                (false, HostArch::Arm) => "gnueabihf",
+                (true, HostArch::Arm) => "musleabihf",
+                (false, _) => "gnu",
+                (true, _) => "musl",
+            };
+
+            format!("{arch}-unknown-linux-{abi}")
+        }
+        HostOS::MacOS => {
+            if !matches!(env.arch, HostArch::X64 | HostArch::Arm64) {
+                return None;
+            }
+
+            format!("{arch}-apple-darwin")
+        }
+        HostOS::Windows => {
+            if env.arch == HostArch::Arm {
+                return None;
+            }
+
+            format!("{arch}-pc-windows-msvc")
+        }
+        _ => return None,
+    };
+
+    Some(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env(os: HostOS, arch: HostArch, libc: HostLibc) -> HostEnvironment {
+        HostEnvironment {
+            arch,
+            libc,
+            os,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn linux_targets() {
+        let gnu = env(HostOS::Linux, HostArch::X64, HostLibc::Gnu);
+        let musl = env(HostOS::Linux, HostArch::Arm64, HostLibc::Musl);
+        let unknown = env(HostOS::Linux, HostArch::X64, HostLibc::Unknown);
+
+        assert_eq!(
+            get_rust_target(&gnu, TargetLibc::Host).unwrap(),
+            "x86_64-unknown-linux-gnu"
+        );
+        assert_eq!(
+            get_rust_target(&musl, TargetLibc::Host).unwrap(),
+            "aarch64-unknown-linux-musl"
+        );
+        assert_eq!(
+            get_rust_target(&unknown, TargetLibc::Host).unwrap(),
+            "x86_64-unknown-linux-gnu"
+        );
+        assert_eq!(
+            get_rust_target(&musl, TargetLibc::Gnu).unwrap(),
+            "aarch64-unknown-linux-gnu"
+        );
+        assert_eq!(
+            get_rust_target(&gnu, TargetLibc::Musl).unwrap(),
+            "x86_64-unknown-linux-musl"
+        );
+    }
+
+    #[test]
+    fn linux_32bit_targets() {
+        let arm = env(HostOS::Linux, HostArch::Arm, HostLibc::Gnu);
+        let x86 = env(HostOS::Linux, HostArch::X86, HostLibc::Gnu);
+
+        assert_eq!(
+            get_rust_target(&arm, TargetLibc::Host).unwrap(),
+            "armv7-unknown-linux-gnueabihf"
+        );
+        assert_eq!(
+            get_rust_target(&arm, TargetLibc::Musl).unwrap(),
+            "armv7-unknown-linux-musleabihf"
+        );
+        assert_eq!(
+            get_rust_target(&x86, TargetLibc::Host).unwrap(),
+            "i686-unknown-linux-gnu"
+        );
+    }
+
+    #[test]
+    fn macos_and_windows_targets() {
+        assert_eq!(
+            get_rust_target(
+                &env(HostOS::MacOS, HostArch::Arm64, HostLibc::Unknown),
+                TargetLibc::Host
+            )
+            .unwrap(),
+            "aarch64-apple-darwin"
+        );
+        assert_eq!(
+            get_rust_target(
+                &env(HostOS::Windows, HostArch::X64, HostLibc::Unknown),
+                TargetLibc::Musl
+            )
+            .unwrap(),
+            "x86_64-pc-windows-msvc"
+        );
+        assert_eq!(
+            get_rust_target(
+                &env(HostOS::Windows, HostArch::X86, HostLibc::Unknown),
+                TargetLibc::Host
+            )
+            .unwrap(),
+            "i686-pc-windows-msvc"
+        );
+    }
+
+    #[test]
+    fn unknown_targets() {
+        assert_eq!(
+            get_rust_target(
+                &env(HostOS::MacOS, HostArch::X86, HostLibc::Unknown),
+                TargetLibc::Host
+            ),
+            None
+        );
+        assert_eq!(
+            get_rust_target(
+                &env(HostOS::Linux, HostArch::S390x, HostLibc::Gnu),
+                TargetLibc::Host
+            ),
+            None
+        );
+        assert_eq!(
+            get_rust_target(
+                &env(HostOS::FreeBSD, HostArch::X64, HostLibc::Unknown),
+                TargetLibc::Host
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,122 @@
+use proto_pdk_api::UnresolvedVersionSpec;
+
+// asdf plugin names that differ from the proto tool identifier
+static ASDF_TOOL_NAMES: [(&str, &str); 2] = [("golang", "go"), ("nodejs", "node")];
+
+fn to_tool_name(name: &str) -> &str {
+    ASDF_TOOL_NAMES
+        .iter()
+        .find(|(asdf, _)| *asdf == name)
+        .map(|(_, tool)| *tool)
+        .unwrap_or(name)
+}
+
+/// Return the versions listed for a tool in a `.tool-versions` (asdf, mise)
+/// file, in order of preference. Entries that can't be installed by proto
+/// (`ref:`, `path:`, and `system`) are skipped.
+///
+/// ```text
+/// # comment
+/// nodejs 20.11.0 18.19.0
+/// python 3.12.1 system # comment
+/// ```
+///
+/// <https://asdf-vm.com/manage/configuration.html#tool-versions>
+pub fn parse_tool_versions<'a>(content: &'a str, tool: &str) -> Vec<&'a str> {
+    for line in content.lines() {
+        let line = line.split_once('#').map(|(line, _)| line).unwrap_or(line);
+        let mut parts = line.split_whitespace();
+
+        if parts.next().is_some_and(|name| to_tool_name(name) == tool) {
+            return parts
+                .filter(|value| {
+                    *value != "system" && !value.starts_with("ref:") && !value.starts_with("path:")
+                })
+                // latest:3.12 is the latest version with a 3.12 prefix
+                .map(|value| match value.strip_prefix("latest:") {
+                    Some(prefix) if !prefix.is_empty() => prefix,
+                    _ => value,
+                })
+                .collect();
+        }
+    }
+
+    vec![]
+}
+
+/// Return the first version listed for a tool in a `.tool-versions`
+/// file that is a valid version specification.
+pub fn find_tool_version(content: &str, tool: &str) -> Option<UnresolvedVersionSpec> {
+    parse_tool_versions(content, tool)
+        .into_iter()
+        .find_map(|value| UnresolvedVersionSpec::parse(value).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_tool_line() {
+        let content = "ruby 3.3.0\nnodejs 20.11.0\n\ngolang 1.22.0\n";
+
+        assert_eq!(parse_tool_versions(content, "ruby"), vec!["3.3.0"]);
+        assert_eq!(parse_tool_versions(content, "node"), vec!["20.11.0"]);
+        assert_eq!(parse_tool_versions(content, "go"), vec!["1.22.0"]);
+        assert!(parse_tool_versions(content, "deno").is_empty());
+    }
+
+    #[test]
+    fn supports_proto_names() {
+        assert_eq!(
+            parse_tool_versions("node 20.11.0\ngo 1.22.0", "node"),
+            vec!["20.11.0"]
+        );
+        assert_eq!(
+            parse_tool_versions("node 20.11.0\ngo 1.22.0", "go"),
+            vec!["1.22.0"]
+        );
+    }
+
+    #[test]
+    fn returns_fallback_versions() {
+        assert_eq!(
+            parse_tool_versions("python 3.12.1 3.11.7 system", "python"),
+            vec!["3.12.1", "3.11.7"]
+        );
+    }
+
+    #[test]
+    fn skips_non_versions() {
+        assert_eq!(
+            parse_tool_versions("rust ref:1a2b3c path:/opt/rust system 1.75.0", "rust"),
+            vec!["1.75.0"]
+        );
+        assert!(parse_tool_versions("bun system", "bun").is_empty());
+    }
+
+    #[test]
+    fn converts_latest_prefix() {
+        assert_eq!(
+            parse_tool_versions("uv latest:0.5 latest", "uv"),
+            vec!["0.5", "latest"]
+        );
+    }
+
+    #[test]
+    fn ignores_comments() {
+        let content = "# just 1.0.0\njust 1.36.0 # pinned for CI\n  # deno 2.0.0\n";
+
+        assert_eq!(parse_tool_versions(content, "just"), vec!["1.36.0"]);
+        assert!(parse_tool_versions(content, "deno").is_empty());
+    }
+
+    #[test]
+    fn finds_first_valid_version() {
+        assert_eq!(
+            find_tool_version("deno path:/opt/deno 2.1.4", "deno"),
+            Some(UnresolvedVersionSpec::parse("2.1.4").unwrap())
+        );
+        assert_eq!(find_tool_version("deno system", "deno"), None);
+    }
+}
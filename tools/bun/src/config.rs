@@ -1,14 +1,38 @@
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    schematic::Schematic,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum BunBaseline {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
 #[derive(Debug, schematic::Schematic, serde::Deserialize, serde::Serialize)]
 #[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
 pub struct BunPluginConfig {
+    pub baseline: BunBaseline,
     pub dist_url: String,
+    pub profile: bool,
+    pub shared_globals_dir: bool,
 }
 
 impl Default for BunPluginConfig {
     fn default() -> Self {
         Self {
+            baseline: BunBaseline::default(),
             dist_url: "https://github.com/oven-sh/bun/releases/download/bun-v{version}/{file}"
                 .into(),
+            profile: false,
+            shared_globals_dir: false,
         }
     }
 }
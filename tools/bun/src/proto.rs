@@ -1,8 +1,11 @@
-use crate::config::BunPluginConfig;
+use crate::config::{BunBaseline, BunPluginConfig};
 use extism_pdk::*;
+use node_common::parse_package_manager_field;
+use nodejs_package_json::PackageJson;
 use proto_pdk::*;
 use schematic::SchemaBuilder;
 use std::collections::HashMap;
+use tool_common::{find_tool_version, BUN_LEGACY_PLATFORMS, BUN_PLATFORMS};
 
 #[host_fn]
 extern "ExtismHost" {
@@ -24,6 +27,60 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
     }))
 }
 
+#[plugin_fn]
+pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
+    Ok(Json(DetectVersionOutput {
+        files: vec![
+            ".bun-version".into(),
+            "package.json".into(),
+            ".tool-versions".into(),
+        ],
+        ignore: vec!["node_modules".into()],
+    }))
+}
+
+#[plugin_fn]
+pub fn parse_version_file(
+    Json(input): Json<ParseVersionFileInput>,
+) -> FnResult<Json<ParseVersionFileOutput>> {
+    let mut version = None;
+
+    if input.file == "package.json" {
+        if let Ok(package_json) = json::from_str::<PackageJson>(&input.content) {
+            if let Some(pm) = &package_json.package_manager {
+                let (name, value) = parse_package_manager_field(pm);
+
+                if name == "bun" {
+                    version = Some(UnresolvedVersionSpec::parse(value.unwrap_or("latest"))?);
+                }
+            }
+
+            if version.is_none() {
+                if let Some(engines) = package_json.engines {
+                    if let Some(constraint) = engines.get("bun") {
+                        version = Some(UnresolvedVersionSpec::parse(constraint)?);
+                    }
+                }
+            }
+        }
+    } else if input.file == ".tool-versions" {
+        version = find_tool_version(&input.content, "bun");
+    } else {
+        for line in input.content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            } else {
+                version = Some(UnresolvedVersionSpec::parse(line)?);
+                break;
+            }
+        }
+    }
+
+    Ok(Json(ParseVersionFileOutput { version }))
+}
+
 #[plugin_fn]
 pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVersionsOutput>> {
     let tags = load_git_tags("https://github.com/oven-sh/bun")?
@@ -34,6 +91,23 @@ pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVers
     Ok(Json(LoadVersionsOutput::from(tags)?))
 }
 
+// https://bun.sh/docs/installation#cpu-requirements-and-baseline-builds
+// Plugins can't read `/proc` from within WASM, so the CPU flags are
+// checked on the host. Returns `None` when they can't be detected.
+fn has_avx2_support(env: &HostEnvironment) -> AnyResult<Option<bool>> {
+    if !env.os.is_linux() || !command_exists(env, "grep") {
+        return Ok(None);
+    }
+
+    let output = exec_command!("grep", ["-q", "avx2", "/proc/cpuinfo"]);
+
+    Ok(match output.exit_code {
+        0 => Some(true),
+        1 => Some(false),
+        _ => None,
+    })
+}
+
 #[plugin_fn]
 pub fn download_prebuilt(
     Json(input): Json<DownloadPrebuiltInput>,
@@ -50,20 +124,32 @@ pub fn download_prebuilt(
         NAME,
         &env,
         if has_windows_support {
-            permutations! [
-                HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-                HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-                HostOS::Windows => [HostArch::X64],
-            ]
+            BUN_PLATFORMS.to_permutations()
         } else {
-            permutations! [
-                HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-                HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            ]
+            BUN_LEGACY_PLATFORMS.to_permutations()
         },
     )?;
 
     let version = &input.context.version;
+    let config = get_tool_config::<BunPluginConfig>()?;
+    let is_musl = env.os.is_linux() && env.libc == HostLibc::Musl;
+
+    if is_musl
+        && version
+            .as_version()
+            .is_some_and(|v| v < &Version::new(1, 1, 35))
+    {
+        return Err(plugin_err!(
+            "Bun musl builds are only available for v1.1.35 and above."
+        ));
+    }
+
+    let os = match env.os {
+        HostOS::Linux => "linux",
+        HostOS::MacOS => "darwin",
+        HostOS::Windows => "windows",
+        _ => unreachable!(),
+    };
 
     let arch = match env.arch {
         HostArch::Arm64 => "aarch64",
@@ -71,25 +157,32 @@ pub fn download_prebuilt(
         _ => unreachable!(),
     };
 
-    let mut avx2_suffix = "";
+    // Baseline builds are for x64 CPUs that don't support AVX2
+    let baseline = env.arch == HostArch::X64
+        && match config.baseline {
+            BunBaseline::Always => true,
+            BunBaseline::Never => false,
+            // Only use the baseline build when the CPU is known to lack AVX2
+            BunBaseline::Auto => has_avx2_support(&env)? == Some(false),
+        };
 
-    if env.arch == HostArch::X64 && env.os.is_linux() && command_exists(&env, "grep") {
-        let output = exec_command!("grep", ["avx2", "/proc/cpuinfo"]);
+    // bun-linux-x64-musl-baseline-profile
+    let mut prefix = format!("bun-{os}-{arch}");
 
-        if output.exit_code != 0 {
-            avx2_suffix = "-baseline";
-        }
-    };
+    if is_musl {
+        prefix.push_str("-musl");
+    }
 
-    let prefix = match env.os {
-        HostOS::Linux => format!("bun-linux-{arch}{avx2_suffix}"),
-        HostOS::MacOS => format!("bun-darwin-{arch}{avx2_suffix}"),
-        HostOS::Windows => format!("bun-windows-{arch}"),
-        _ => unreachable!(),
-    };
+    if baseline {
+        prefix.push_str("-baseline");
+    }
+
+    if config.profile {
+        prefix.push_str("-profile");
+    }
 
     let filename = format!("{prefix}.zip");
-    let mut host = get_tool_config::<BunPluginConfig>()?.dist_url;
+    let mut host = config.dist_url;
 
     // canary - bun-v1.2.3
     if version.is_canary() {
@@ -120,12 +213,18 @@ pub fn locate_executables(
     Json(_): Json<LocateExecutablesInput>,
 ) -> FnResult<Json<LocateExecutablesOutput>> {
     let env = get_host_environment()?;
+    let config = get_tool_config::<BunPluginConfig>()?;
+
+    // Profile builds ship a `bun-profile` binary with debug symbols
+    let exe_name = env
+        .os
+        .get_exe_name(if config.profile { "bun-profile" } else { "bun" });
 
     let bunx = ExecutableConfig {
         // `bunx` isn't a real binary provided by Bun so we can't symlink it.
         // Instead, it's simply the `bun` binary named `bunx` and Bun toggles
         // functionality based on `args[0]`.
-        exe_link_path: Some(env.os.get_exe_name("bun").into()),
+        exe_link_path: Some(exe_name.clone().into()),
 
         // The approach doesn't work for shims since we use child processes,
         // so execute `bun x` instead (notice the space).
@@ -136,13 +235,59 @@ pub fn locate_executables(
 
     Ok(Json(LocateExecutablesOutput {
         exes: HashMap::from_iter([
-            (
-                "bun".into(),
-                ExecutableConfig::new_primary(env.os.get_exe_name("bun")),
-            ),
+            ("bun".into(), ExecutableConfig::new_primary(exe_name)),
             ("bunx".into(), bunx),
         ]),
-        globals_lookup_dirs: vec!["$HOME/.bun/bin".into()],
+        globals_lookup_dirs: if config.shared_globals_dir {
+            vec!["$PROTO_HOME/tools/bun/globals/bin".into()]
+        } else {
+            vec!["$BUN_INSTALL/bin".into(), "$HOME/.bun/bin".into()]
+        },
         ..LocateExecutablesOutput::default()
     }))
 }
+
+#[plugin_fn]
+pub fn pre_run(Json(input): Json<RunHook>) -> FnResult<Json<RunHookResult>> {
+    let mut result = RunHookResult::default();
+
+    let Some(globals_dir) = &input.globals_dir else {
+        return Ok(Json(result));
+    };
+
+    let args = &input.passthrough_args;
+    let config = get_tool_config::<BunPluginConfig>()?;
+
+    if args.len() < 2 || !config.shared_globals_dir {
+        return Ok(Json(result));
+    }
+
+    // bun add|install|remove|etc -g <dep>
+    let aliases = ["add", "a", "install", "i", "remove", "rm", "update", "pm"];
+
+    if aliases.iter().any(|alias| *alias == args[0])
+        && args.iter().any(|arg| arg == "--global" || arg == "-g")
+    {
+        let Some(globals_bin_dir) = globals_dir.real_path() else {
+            return Ok(Json(result));
+        };
+
+        let vars = result.env.get_or_insert(HashMap::default());
+
+        // Packages are installed to $BUN_INSTALL/install/global,
+        // while binaries are linked in $BUN_INSTALL_BIN
+        if let Some(globals_root_dir) = globals_bin_dir.parent() {
+            vars.insert(
+                "BUN_INSTALL".into(),
+                globals_root_dir.to_string_lossy().to_string(),
+            );
+        }
+
+        vars.insert(
+            "BUN_INSTALL_BIN".into(),
+            globals_bin_dir.to_string_lossy().to_string(),
+        );
+    }
+
+    Ok(Json(result))
+}
@@ -1,5 +1,13 @@
 use proto_pdk_test_utils::*;
 
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct BunPluginConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<String>,
+    pub profile: bool,
+}
+
 mod bun_tool {
     use super::*;
 
@@ -231,4 +239,270 @@ mod bun_tool {
             Some("bun.exe".into())
         );
     }
+
+    mod variants {
+        use super::*;
+
+        async fn get_archive_prefix(
+            os: HostOS,
+            arch: HostArch,
+            libc: HostLibc,
+            config: BunPluginConfig,
+        ) -> String {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("bun-test", |builder| {
+                    builder.host_environment(HostEnvironment {
+                        arch,
+                        libc,
+                        os,
+                        ..Default::default()
+                    });
+                    builder.tool_config(&config);
+                })
+                .await;
+
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("1.2.0").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await
+                .archive_prefix
+                .unwrap()
+        }
+
+        fn baseline(value: &str) -> BunPluginConfig {
+            BunPluginConfig {
+                baseline: Some(value.into()),
+                ..Default::default()
+            }
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn linux_x64_gnu() {
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Linux,
+                    HostArch::X64,
+                    HostLibc::Gnu,
+                    baseline("never")
+                )
+                .await,
+                "bun-linux-x64"
+            );
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Linux,
+                    HostArch::X64,
+                    HostLibc::Gnu,
+                    baseline("always")
+                )
+                .await,
+                "bun-linux-x64-baseline"
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn linux_x64_musl() {
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Linux,
+                    HostArch::X64,
+                    HostLibc::Musl,
+                    baseline("never")
+                )
+                .await,
+                "bun-linux-x64-musl"
+            );
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Linux,
+                    HostArch::X64,
+                    HostLibc::Musl,
+                    baseline("always")
+                )
+                .await,
+                "bun-linux-x64-musl-baseline"
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn linux_arm64_musl() {
+            // Baseline builds are only for x64
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Linux,
+                    HostArch::Arm64,
+                    HostLibc::Musl,
+                    baseline("always")
+                )
+                .await,
+                "bun-linux-aarch64-musl"
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn profile() {
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Linux,
+                    HostArch::X64,
+                    HostLibc::Gnu,
+                    BunPluginConfig {
+                        baseline: Some("never".into()),
+                        profile: true,
+                    }
+                )
+                .await,
+                "bun-linux-x64-profile"
+            );
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Linux,
+                    HostArch::X64,
+                    HostLibc::Musl,
+                    BunPluginConfig {
+                        baseline: Some("always".into()),
+                        profile: true,
+                    }
+                )
+                .await,
+                "bun-linux-x64-musl-baseline-profile"
+            );
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::MacOS,
+                    HostArch::Arm64,
+                    HostLibc::Unknown,
+                    BunPluginConfig {
+                        profile: true,
+                        ..Default::default()
+                    }
+                )
+                .await,
+                "bun-darwin-aarch64-profile"
+            );
+        }
+
+        #[cfg(target_os = "linux")]
+        #[tokio::test(flavor = "multi_thread")]
+        async fn linux_x64_auto() {
+            // The CPU flags are read on the host running the tests
+            let has_avx2 = std::fs::read_to_string("/proc/cpuinfo")
+                .unwrap()
+                .contains("avx2");
+
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Linux,
+                    HostArch::X64,
+                    HostLibc::Gnu,
+                    baseline("auto")
+                )
+                .await,
+                if has_avx2 {
+                    "bun-linux-x64"
+                } else {
+                    "bun-linux-x64-baseline"
+                }
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn macos_and_windows_x64() {
+            // Auto only detects baseline on Linux
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::MacOS,
+                    HostArch::X64,
+                    HostLibc::Unknown,
+                    baseline("auto")
+                )
+                .await,
+                "bun-darwin-x64"
+            );
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::MacOS,
+                    HostArch::X64,
+                    HostLibc::Unknown,
+                    baseline("always")
+                )
+                .await,
+                "bun-darwin-x64-baseline"
+            );
+            assert_eq!(
+                get_archive_prefix(
+                    HostOS::Windows,
+                    HostArch::X64,
+                    HostLibc::Unknown,
+                    baseline("always")
+                )
+                .await,
+                "bun-windows-x64-baseline"
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        #[should_panic(expected = "Bun musl builds are only available for v1.1.35 and above.")]
+        async fn doesnt_support_old_musl() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("bun-test", |config| {
+                    config.host_environment(HostEnvironment {
+                        arch: HostArch::X64,
+                        libc: HostLibc::Musl,
+                        os: HostOS::Linux,
+                        ..Default::default()
+                    });
+                })
+                .await;
+
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("1.1.0").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await;
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn locates_profile_bin() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("bun-test", |config| {
+                    config.host(HostOS::Linux, HostArch::X64);
+                    config.tool_config(BunPluginConfig {
+                        profile: true,
+                        ..Default::default()
+                    });
+                })
+                .await;
+
+            let output = plugin
+                .locate_executables(LocateExecutablesInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("1.2.0").unwrap(),
+                        ..Default::default()
+                    },
+                })
+                .await;
+
+            assert_eq!(
+                output.exes.get("bun").unwrap().exe_path,
+                Some("bun-profile".into())
+            );
+            assert_eq!(
+                output.exes.get("bunx").unwrap().exe_link_path,
+                Some("bun-profile".into())
+            );
+        }
+    }
 }
@@ -0,0 +1,149 @@
+use proto_pdk_test_utils::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct BunPluginConfig {
+    pub shared_globals_dir: bool,
+}
+
+mod bun_tool {
+    use super::*;
+
+    mod pre_run {
+        use super::*;
+
+        fn create_globals_dir() -> VirtualPath {
+            VirtualPath::WithReal {
+                path: PathBuf::from("/proto/tools/bun/globals/bin"),
+                virtual_prefix: PathBuf::from("/proto"),
+                real_prefix: PathBuf::from("/.proto"),
+            }
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn does_nothing_if_not_configured() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox.create_plugin("bun-test").await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    globals_dir: Some(create_globals_dir()),
+                    passthrough_args: vec!["add".into(), "-g".into(), "typescript".into()],
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(result.args, None);
+            assert_eq!(result.env, None);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn does_nothing_if_not_global() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("bun-test", |config| {
+                    config.tool_config(BunPluginConfig {
+                        shared_globals_dir: true,
+                    });
+                })
+                .await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    globals_dir: Some(create_globals_dir()),
+                    passthrough_args: vec!["add".into(), "typescript".into()],
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(result.args, None);
+            assert_eq!(result.env, None);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn sets_env_vars_for_global_installs() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("bun-test", |config| {
+                    config.tool_config(BunPluginConfig {
+                        shared_globals_dir: true,
+                    });
+                })
+                .await;
+
+            for args in [
+                ["add", "-g", "typescript"],
+                ["install", "--global", "typescript"],
+                ["remove", "-g", "typescript"],
+            ] {
+                let result = plugin
+                    .pre_run(RunHook {
+                        globals_dir: Some(create_globals_dir()),
+                        passthrough_args: args.iter().map(|arg| arg.to_string()).collect(),
+                        ..RunHook::default()
+                    })
+                    .await;
+
+                assert_eq!(
+                    result.env,
+                    Some(HashMap::from_iter([
+                        (
+                            "BUN_INSTALL".into(),
+                            PathBuf::from("/.proto/tools/bun/globals")
+                                .to_string_lossy()
+                                .to_string()
+                        ),
+                        (
+                            "BUN_INSTALL_BIN".into(),
+                            PathBuf::from("/.proto/tools/bun/globals/bin")
+                                .to_string_lossy()
+                                .to_string()
+                        ),
+                    ]))
+                );
+            }
+        }
+    }
+
+    mod locate_executables {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn uses_bun_install_by_default() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox.create_plugin("bun-test").await;
+
+            let output = plugin
+                .locate_executables(LocateExecutablesInput::default())
+                .await;
+
+            assert_eq!(
+                output.globals_lookup_dirs,
+                vec!["$BUN_INSTALL/bin", "$HOME/.bun/bin"]
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn uses_shared_globals_dir() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("bun-test", |config| {
+                    config.tool_config(BunPluginConfig {
+                        shared_globals_dir: true,
+                    });
+                })
+                .await;
+
+            let output = plugin
+                .locate_executables(LocateExecutablesInput::default())
+                .await;
+
+            assert_eq!(
+                output.globals_lookup_dirs,
+                vec!["$PROTO_HOME/tools/bun/globals/bin"]
+            );
+        }
+    }
+}
@@ -0,0 +1,143 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Deno does not support a version field, so we support an
+// engines-style field, or a custom top-level "deno" field.
+// https://docs.deno.com/runtime/fundamentals/configuration/
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct DenoJson {
+    pub deno: Option<String>,
+    pub engines: HashMap<String, String>,
+}
+
+impl DenoJson {
+    pub fn parse(content: &str) -> Option<Self> {
+        serde_json::from_str(&strip_json_comments(content)).ok()
+    }
+
+    pub fn get_version(mut self) -> Option<String> {
+        self.deno.or_else(|| self.engines.remove("deno"))
+    }
+}
+
+// deno.jsonc (and deno.json) allows comments and trailing commas,
+// which are not supported by serde_json, so remove them
+pub fn strip_json_comments(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+    // Commas are only written once we know they are not trailing
+    let mut pending_comma: Option<usize> = None;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            output.push(c);
+
+            if c == '\\' {
+                if let Some(next) = chars.next() {
+                    output.push(next);
+                }
+            } else if c == '"' {
+                in_string = false;
+            }
+
+            continue;
+        }
+
+        match (c, chars.peek()) {
+            ('/', Some('/')) => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        output.push(next);
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+
+                let mut last = ' ';
+
+                for next in chars.by_ref() {
+                    if last == '*' && next == '/' {
+                        break;
+                    }
+
+                    last = next;
+                }
+            }
+            (',', _) => {
+                pending_comma = Some(output.len());
+            }
+            _ => {
+                if !c.is_whitespace() {
+                    if let Some(index) = pending_comma.take() {
+                        if c != '}' && c != ']' {
+                            output.insert(index, ',');
+                        }
+                    }
+
+                    in_string = c == '"';
+                }
+
+                output.push(c);
+            }
+        };
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_comments() {
+        assert_eq!(
+            strip_json_comments("{\n  // comment\n  \"a\": 1 /* inline */\n}"),
+            "{\n  \n  \"a\": 1 \n}"
+        );
+        assert_eq!(
+            strip_json_comments("{ \"url\": \"https://deno.land/*\" }"),
+            "{ \"url\": \"https://deno.land/*\" }"
+        );
+        assert_eq!(
+            strip_json_comments("{ \"a\": \"\\\"//\" }"),
+            "{ \"a\": \"\\\"//\" }"
+        );
+    }
+
+    #[test]
+    fn strips_trailing_commas() {
+        assert_eq!(
+            strip_json_comments("{ \"a\": [1, 2,], \"b\": 3, // comment\n}"),
+            "{ \"a\": [1, 2], \"b\": 3 \n}"
+        );
+    }
+
+    #[test]
+    fn parses_versions() {
+        assert_eq!(
+            DenoJson::parse("{ \"deno\": \"2.1.4\" }")
+                .unwrap()
+                .get_version(),
+            Some("2.1.4".into())
+        );
+        assert_eq!(
+            DenoJson::parse("{\n  // comment\n  \"engines\": { \"deno\": \"^2\", },\n}")
+                .unwrap()
+                .get_version(),
+            Some("^2".into())
+        );
+        assert_eq!(
+            DenoJson::parse("{ \"tasks\": { \"dev\": \"deno run main.ts\" } }")
+                .unwrap()
+                .get_version(),
+            None
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod config;
+mod deno_json;
 
 #[cfg(feature = "wasm")]
 mod proto;
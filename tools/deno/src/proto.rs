@@ -1,8 +1,13 @@
 use crate::config::DenoPluginConfig;
+use crate::deno_json::DenoJson;
 use extism_pdk::*;
+use nodejs_package_json::PackageJson;
 use proto_pdk::*;
 use schematic::SchemaBuilder;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::{fs, io};
+use tool_common::{find_tool_version, get_rust_target, TargetLibc, DENO_PLATFORMS};
 
 #[host_fn]
 extern "ExtismHost" {
@@ -27,11 +32,57 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
 #[plugin_fn]
 pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
     Ok(Json(DetectVersionOutput {
-        files: vec![".dvmrc".into()],
-        ignore: vec![],
+        files: vec![
+            ".dvmrc".into(),
+            "deno.json".into(),
+            "deno.jsonc".into(),
+            "package.json".into(),
+            ".tool-versions".into(),
+        ],
+        ignore: vec!["node_modules".into()],
     }))
 }
 
+#[plugin_fn]
+pub fn parse_version_file(
+    Json(input): Json<ParseVersionFileInput>,
+) -> FnResult<Json<ParseVersionFileOutput>> {
+    let mut version = None;
+
+    if input.file == "deno.json" || input.file == "deno.jsonc" {
+        if let Some(constraint) =
+            DenoJson::parse(&input.content).and_then(|deno| deno.get_version())
+        {
+            version = Some(UnresolvedVersionSpec::parse(constraint)?);
+        }
+    } else if input.file == "package.json" {
+        if let Ok(package_json) = json::from_str::<PackageJson>(&input.content) {
+            if let Some(constraint) = package_json
+                .engines
+                .as_ref()
+                .and_then(|engines| engines.get("deno"))
+            {
+                version = Some(UnresolvedVersionSpec::parse(constraint)?);
+            }
+        }
+    } else if input.file == ".tool-versions" {
+        version = find_tool_version(&input.content, "deno");
+    } else {
+        for line in input.content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            } else {
+                version = Some(UnresolvedVersionSpec::parse(line)?);
+                break;
+            }
+        }
+    }
+
+    Ok(Json(ParseVersionFileOutput { version }))
+}
+
 #[plugin_fn]
 pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVersionsOutput>> {
     let tags = load_git_tags("https://github.com/denoland/deno")?
@@ -42,6 +93,28 @@ pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVers
     Ok(Json(LoadVersionsOutput::from(tags)?))
 }
 
+// Channels are published as text files containing the latest tag
+// https://dl.deno.land/release-rc-latest.txt
+// https://dl.deno.land/release-lts-latest.txt
+#[plugin_fn]
+pub fn resolve_version(
+    Json(input): Json<ResolveVersionInput>,
+) -> FnResult<Json<ResolveVersionOutput>> {
+    let mut output = ResolveVersionOutput::default();
+
+    if let UnresolvedVersionSpec::Alias(alias) = input.initial {
+        if alias == "rc" || alias == "lts" {
+            let tag = fetch_text(format!("https://dl.deno.land/release-{alias}-latest.txt"))?;
+
+            output.candidate = Some(UnresolvedVersionSpec::parse(
+                tag.trim().trim_start_matches('v'),
+            )?);
+        }
+    }
+
+    Ok(Json(output))
+}
+
 // https://docs.deno.com/runtime/contributing/building_from_source/
 #[plugin_fn]
 pub fn build_instructions(
@@ -50,15 +123,7 @@ pub fn build_instructions(
     let env = get_host_environment()?;
     let version = input.context.version;
 
-    check_supported_os_and_arch(
-        NAME,
-        &env,
-        permutations! [
-            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            HostOS::Windows => [HostArch::X64],
-        ],
-    )?;
+    check_supported_os_and_arch(NAME, &env, DENO_PLATFORMS.to_permutations())?;
 
     let mut output = BuildInstructionsOutput {
         // source: Some(SourceLocation::Git(GitSource {
@@ -144,29 +209,18 @@ pub fn download_prebuilt(
 ) -> FnResult<Json<DownloadPrebuiltOutput>> {
     let env = get_host_environment()?;
 
-    check_supported_os_and_arch(
-        NAME,
-        &env,
-        permutations! [
-            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            HostOS::Windows => [HostArch::X64],
-        ],
-    )?;
+    check_supported_os_and_arch(NAME, &env, DENO_PLATFORMS.to_permutations())?;
 
-    let version = &input.context.version;
+    if env.libc == HostLibc::Musl {
+        return Err(plugin_err!(
+            "Deno does not publish pre-builts for musl, only glibc. Try a glibc based distribution, or run Deno in a container."
+        ));
+    }
 
-    let arch = match env.arch {
-        HostArch::Arm64 => "aarch64",
-        HostArch::X64 => "x86_64",
-        _ => unreachable!(),
-    };
+    let version = &input.context.version;
 
-    let target = match env.os {
-        HostOS::Linux => format!("{arch}-unknown-linux-gnu"),
-        HostOS::MacOS => format!("{arch}-apple-darwin"),
-        HostOS::Windows => format!("{arch}-pc-windows-msvc"),
-        _ => unreachable!(),
+    let Some(target) = get_rust_target(&env, TargetLibc::Gnu) else {
+        unreachable!();
     };
 
     let filename = format!("deno-{target}.zip");
@@ -188,13 +242,46 @@ pub fn download_prebuilt(
             .replace("{file}", &filename)
     };
 
+    // Checksums were not published for older versions
+    let checksum_url = if version.is_canary()
+        || version
+            .as_version()
+            .is_some_and(|v| v < &Version::new(1, 46, 0))
+    {
+        None
+    } else {
+        Some(format!("{download_url}.sha256sum"))
+    };
+
     Ok(Json(DownloadPrebuiltOutput {
+        checksum_url,
         download_url,
         download_name: Some(filename),
         ..DownloadPrebuiltOutput::default()
     }))
 }
 
+#[plugin_fn]
+pub fn verify_checksum(
+    Json(input): Json<VerifyChecksumInput>,
+) -> FnResult<Json<VerifyChecksumOutput>> {
+    let mut file = fs::File::open(&input.download_file)?;
+    let mut sha = Sha256::new();
+
+    io::copy(&mut file, &mut sha)?;
+
+    let checksum = format!("{:x}", sha.finalize());
+
+    // Unix checksums are in the format of "<checksum>  <file>", while Windows
+    // checksums are generated with PowerShell: "Hash : <CHECKSUM>"
+    let verified = fs::read_to_string(&input.checksum_file)?
+        .to_lowercase()
+        .lines()
+        .any(|line| line.split_whitespace().any(|part| part == checksum));
+
+    Ok(Json(VerifyChecksumOutput { verified }))
+}
+
 #[plugin_fn]
 pub fn locate_executables(
     Json(_): Json<LocateExecutablesInput>,
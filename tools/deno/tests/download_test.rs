@@ -184,6 +184,64 @@ mod deno_tool {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn includes_checksum_for_newer_versions() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("deno-test", |config| {
+                config.host(HostOS::MacOS, HostArch::Arm64);
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("2.1.4").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                checksum_url: Some(
+                    "https://dl.deno.land/release/v2.1.4/deno-aarch64-apple-darwin.zip.sha256sum"
+                        .into()
+                ),
+                download_name: Some("deno-aarch64-apple-darwin.zip".into()),
+                download_url: "https://dl.deno.land/release/v2.1.4/deno-aarch64-apple-darwin.zip"
+                    .into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "Deno does not publish pre-builts for musl")]
+    async fn doesnt_support_linux_musl() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("deno-test", |config| {
+                config.host_environment(HostEnvironment {
+                    arch: HostArch::X64,
+                    libc: HostLibc::Musl,
+                    os: HostOS::Linux,
+                    ..Default::default()
+                });
+            })
+            .await;
+
+        plugin
+            .download_prebuilt(DownloadPrebuiltInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("2.1.4").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await;
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn locates_unix_bin() {
         let sandbox = create_empty_proto_sandbox();
@@ -30,4 +30,135 @@ mod deno_tool {
         assert!(output.aliases.contains_key("latest"));
         assert_eq!(output.aliases.get("latest"), output.latest.as_ref());
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn resolves_channel_aliases() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("deno-test").await;
+
+        for alias in ["rc", "lts"] {
+            let output = plugin
+                .resolve_version(ResolveVersionInput {
+                    initial: UnresolvedVersionSpec::Alias(alias.into()),
+                })
+                .await;
+
+            assert!(matches!(
+                output.candidate,
+                Some(UnresolvedVersionSpec::Semantic(_))
+            ));
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_dvmrc() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("deno-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "1.40.0\n".into(),
+                    file: ".dvmrc".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("1.40.0").unwrap()),
+            }
+        );
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "nodejs 20.10.0\ndeno 2.1.4\n".into(),
+                    file: ".tool-versions".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("2.1.4").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_deno_json() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("deno-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: r#"{ "deno": "2.1.4", "tasks": { "dev": "deno run main.ts" } }"#
+                        .into(),
+                    file: "deno.json".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("2.1.4").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_deno_jsonc() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("deno-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: r#"{
+  // Pin the Deno version
+  "engines": {
+    "deno": "^2.1", /* trailing comma */
+  },
+}"#
+                    .into(),
+                    file: "deno.jsonc".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("^2.1").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_package_json_engines() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("deno-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: r#"{ "engines": { "deno": ">=1.40.0" } }"#.into(),
+                    file: "package.json".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse(">=1.40.0").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn skips_deno_json_without_version() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("deno-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: r#"{ "imports": { "@std/assert": "jsr:@std/assert@^1.0.0" } }"#.into(),
+                    file: "deno.json".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput { version: None }
+        );
+    }
 }
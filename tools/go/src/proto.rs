@@ -4,6 +4,7 @@ use extism_pdk::*;
 use proto_pdk::*;
 use schematic::SchemaBuilder;
 use std::collections::HashMap;
+use tool_common::parse_tool_versions;
 
 #[host_fn]
 extern "ExtismHost" {
@@ -27,7 +28,7 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
 #[plugin_fn]
 pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
     Ok(Json(DetectVersionOutput {
-        files: vec!["go.mod".into(), "go.work".into()],
+        files: vec!["go.mod".into(), "go.work".into(), ".tool-versions".into()],
         ignore: vec![],
     }))
 }
@@ -58,6 +59,11 @@ pub fn parse_version_file(
                 break;
             }
         }
+    } else if input.file == ".tool-versions" {
+        // Multiple versions may be listed, so fall through to the next valid one
+        version = parse_tool_versions(&input.content, "go")
+            .into_iter()
+            .find_map(|value| UnresolvedVersionSpec::parse(from_go_version(value)).ok());
     }
 
     Ok(Json(ParseVersionFileOutput { version }))
@@ -57,6 +57,18 @@ require (
             output.version.unwrap(),
             UnresolvedVersionSpec::parse("^1.20.0").unwrap()
         );
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "nodejs 20.10.0\ngolang 1.21.5\n".into(),
+                    file: ".tool-versions".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("1.21.5").unwrap()),
+            }
+        );
     }
 
     #[tokio::test(flavor = "multi_thread")]
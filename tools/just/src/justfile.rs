@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+// Justfiles don't support a version requirement, so we support a comment
+// directive in the header (before the first recipe or setting):
+//   # just >= 1.25.0
+//   # just: ^1.36
+pub fn parse_justfile_version(content: &str) -> Option<String> {
+    for line in content.lines() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with("#!") {
+            continue;
+        }
+
+        // Stop once the header comments have ended
+        let comment = line.strip_prefix('#')?.trim();
+
+        let Some(rest) = comment.strip_prefix("just") else {
+            continue;
+        };
+
+        if !rest.starts_with([' ', ':']) {
+            continue;
+        }
+
+        let value = rest.trim_start_matches(':').trim();
+
+        if value.starts_with(|c: char| c.is_ascii_digit() || "^~<>=".contains(c)) {
+            return Some(value.replace(' ', ""));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_directives() {
+        assert_eq!(
+            parse_justfile_version("# just >= 1.25.0\n\nbuild:\n  cargo build"),
+            Some(">=1.25.0".into())
+        );
+        assert_eq!(
+            parse_justfile_version("#!/usr/bin/env just --justfile\n# just: ^1.36\n"),
+            Some("^1.36".into())
+        );
+        assert_eq!(
+            parse_justfile_version("# Tasks for the repo\n# just 1.36.0\n"),
+            Some("1.36.0".into())
+        );
+    }
+
+    #[test]
+    fn ignores_other_comments() {
+        assert_eq!(
+            parse_justfile_version("# justfile for building\n# just run the tasks\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn only_parses_the_header() {
+        assert_eq!(
+            parse_justfile_version("set shell := [\"bash\", \"-c\"]\n\n# just >= 1.25.0\n"),
+            None
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod config;
+mod justfile;
 
 #[cfg(feature = "wasm")]
 mod proto;
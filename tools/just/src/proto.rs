@@ -1,8 +1,12 @@
 use crate::config::JustPluginConfig;
+use crate::justfile::parse_justfile_version;
 use extism_pdk::*;
 use proto_pdk::*;
 use schematic::SchemaBuilder;
 use std::collections::HashMap;
+use tool_common::{
+    find_tool_version, get_archive_file, get_rust_target, TargetLibc, UnixArchive, JUST_PLATFORMS,
+};
 
 #[host_fn]
 extern "ExtismHost" {
@@ -23,6 +27,46 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
     }))
 }
 
+#[plugin_fn]
+pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
+    Ok(Json(DetectVersionOutput {
+        files: vec![
+            ".just-version".into(),
+            "justfile".into(),
+            "Justfile".into(),
+            ".justfile".into(),
+            ".tool-versions".into(),
+        ],
+        ignore: vec![],
+    }))
+}
+
+#[plugin_fn]
+pub fn parse_version_file(
+    Json(input): Json<ParseVersionFileInput>,
+) -> FnResult<Json<ParseVersionFileOutput>> {
+    let mut version = None;
+
+    if input.file == ".just-version" {
+        for line in input.content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            } else {
+                version = Some(UnresolvedVersionSpec::parse(line)?);
+                break;
+            }
+        }
+    } else if input.file == ".tool-versions" {
+        version = find_tool_version(&input.content, "just");
+    } else if let Some(constraint) = parse_justfile_version(&input.content) {
+        version = Some(UnresolvedVersionSpec::parse(constraint)?);
+    }
+
+    Ok(Json(ParseVersionFileOutput { version }))
+}
+
 #[plugin_fn]
 pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVersionsOutput>> {
     let tags = load_git_tags("https://github.com/casey/just")?
@@ -39,32 +83,30 @@ pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVers
     Ok(Json(LoadVersionsOutput::from(tags)?))
 }
 
+// Targets that were first published after the initial release.
+// https://github.com/casey/just/releases
+static TARGETS_SINCE: [(HostOS, HostArch, (u64, u64, u64)); 4] = [
+    (HostOS::Linux, HostArch::Arm64, (0, 8, 4)),
+    (HostOS::Linux, HostArch::Arm, (0, 9, 0)),
+    (HostOS::MacOS, HostArch::Arm64, (0, 10, 4)),
+    (HostOS::Windows, HostArch::Arm64, (1, 29, 0)),
+];
+
+// Checksum files published for each release range, where the last
+// range that the version falls into wins. Older releases published a
+// checksum file for each asset, while newer releases publish a manifest.
+static CHECKSUMS_SINCE: [((u64, u64, u64), &str); 2] =
+    [((0, 0, 0), "{file}.sha256sum"), ((1, 37, 0), "SHA256SUMS")];
+
 #[plugin_fn]
 pub fn download_prebuilt(
     Json(input): Json<DownloadPrebuiltInput>,
 ) -> FnResult<Json<DownloadPrebuiltOutput>> {
     let env = get_host_environment()?;
 
-    check_supported_os_and_arch(
-        NAME,
-        &env,
-        permutations! [
-            HostOS::Linux => [HostArch::X64, HostArch::Arm64, HostArch::Arm],
-            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            HostOS::Windows => [HostArch::X64, HostArch::X86, HostArch::Arm64],
-        ],
-    )?;
+    check_supported_os_and_arch(NAME, &env, JUST_PLATFORMS.to_permutations())?;
 
     let version = &input.context.version;
-    let host = get_tool_config::<JustPluginConfig>()?.dist_url;
-
-    let arch = match env.arch {
-        HostArch::Arm => "arm",
-        HostArch::Arm64 => "aarch64",
-        HostArch::X64 => "x86_64",
-        HostArch::X86 => "x86_64",
-        _ => unreachable!(),
-    };
 
     if version.is_canary() {
         return Err(plugin_err!(PluginError::UnsupportedCanary {
@@ -72,29 +114,46 @@ pub fn download_prebuilt(
         }));
     }
 
-    let prefix = match env.os {
-        HostOS::Linux => format!("just-{version}-{arch}-unknown-linux-musl"),
-        HostOS::MacOS => format!("just-{version}-{arch}-apple-darwin"),
-        HostOS::Windows => format!("just-{version}-{arch}-pc-windows-msvc"),
-        _ => unreachable!(),
+    // Linux builds are statically linked with musl,
+    // so they work on glibc hosts as well
+    let Some(target) = get_rust_target(&env, TargetLibc::Musl) else {
+        unreachable!();
     };
 
-    let filename = if env.os.is_windows() {
-        format!("{prefix}.zip")
-    } else {
-        format!("{prefix}.tar.gz")
-    };
+    if let Some((_, _, (major, minor, patch))) = TARGETS_SINCE
+        .iter()
+        .find(|(os, arch, _)| *os == env.os && *arch == env.arch)
+    {
+        let since = Version::new(*major, *minor, *patch);
+
+        if version.as_version().is_some_and(|v| v < &since) {
+            return Err(plugin_err!(
+                "Just pre-builts for <id>{target}</id> are only available for v{since} and above."
+            ));
+        }
+    }
 
-    let version_spec = VersionSpec::parse("1.37.0").expect("Failed to parse version spec");
+    let filename = get_archive_file(
+        &format!("just-{version}-{target}"),
+        env.os,
+        UnixArchive::TarGz,
+    );
+
+    // Find the last range that the version falls into
+    let checksum_file = CHECKSUMS_SINCE
+        .iter()
+        .rev()
+        .find(|((major, minor, patch), _)| {
+            version
+                .as_version()
+                .is_none_or(|v| v >= &Version::new(*major, *minor, *patch))
+        })
+        .map(|(_, file)| file.replace("{file}", &filename))
+        .unwrap_or_default();
 
-    let checksum_file = if version >= &version_spec {
-        "SHA256SUMS".to_string()
-    } else {
-        format!("{filename}.sha256sum")
-    };
+    let host = get_tool_config::<JustPluginConfig>()?.dist_url;
 
     Ok(Json(DownloadPrebuiltOutput {
-        archive_prefix: Some(prefix),
         download_url: host
             .replace("{version}", &version.to_string())
             .replace("{file}", &filename),
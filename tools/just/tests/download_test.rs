@@ -1,5 +1,24 @@
 use proto_pdk_test_utils::*;
 
+async fn download_prebuilt(os: HostOS, arch: HostArch, version: &str) -> DownloadPrebuiltOutput {
+    let sandbox = create_empty_proto_sandbox();
+    let plugin = sandbox
+        .create_plugin_with_config("just-test", |config| {
+            config.host(os, arch);
+        })
+        .await;
+
+    plugin
+        .download_prebuilt(DownloadPrebuiltInput {
+            context: ToolContext {
+                version: VersionSpec::parse(version).unwrap(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await
+}
+
 generate_download_install_tests!("just-test", "1.36.0");
 
 #[tokio::test(flavor = "multi_thread")]
@@ -22,7 +41,6 @@ async fn supports_linux_arm64() {
             })
             .await,
         DownloadPrebuiltOutput {
-            archive_prefix: Some("just".into()),
             checksum_url: Some("https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-aarch64-unknown-linux-musl.tar.gz.sha256sum".into()),
             download_name: Some("just-1.36.0-aarch64-unknown-linux-musl.tar.gz".into()),
             download_url: "https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-aarch64-unknown-linux-musl.tar.gz".into(),
@@ -51,7 +69,6 @@ async fn supports_linux_x64() {
             })
             .await,
         DownloadPrebuiltOutput {
-            archive_prefix: Some("just".into()),
             checksum_url: Some("https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-x86_64-unknown-linux-musl.tar.gz.sha256sum".into()),
             download_name: Some("just-1.36.0-x86_64-unknown-linux-musl.tar.gz".into()),
             download_url: "https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-x86_64-unknown-linux-musl.tar.gz".into(),
@@ -80,7 +97,6 @@ async fn supports_macos_arm64() {
             })
             .await,
         DownloadPrebuiltOutput {
-            archive_prefix: Some("just".into()),
             checksum_url: Some("https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-aarch64-apple-darwin.tar.gz.sha256sum".into()),
             download_name: Some("just-1.36.0-aarch64-apple-darwin.tar.gz".into()),
             download_url: "https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-aarch64-apple-darwin.tar.gz".into(),
@@ -109,7 +125,6 @@ async fn supports_macos_x64() {
             })
             .await,
         DownloadPrebuiltOutput {
-            archive_prefix: Some("just".into()),
             checksum_url: Some("https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-x86_64-apple-darwin.tar.gz.sha256sum".into()),
             download_name: Some("just-1.36.0-x86_64-apple-darwin.tar.gz".into()),
             download_url: "https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-x86_64-apple-darwin.tar.gz".into(),
@@ -138,7 +153,6 @@ async fn supports_windows_arm64() {
             })
             .await,
         DownloadPrebuiltOutput {
-            archive_prefix: Some("just".into()),
             checksum_url: Some("https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-aarch64-pc-windows-msvc.zip.sha256sum".into()),
             download_name: Some("just-1.36.0-aarch64-pc-windows-msvc.zip".into()),
             download_url: "https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-aarch64-pc-windows-msvc.zip".into(),
@@ -167,7 +181,6 @@ async fn supports_windows_x64() {
             })
             .await,
         DownloadPrebuiltOutput {
-            archive_prefix: Some("just".into()),
             checksum_url: Some("https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-x86_64-pc-windows-msvc.zip.sha256sum".into()),
             download_name: Some("just-1.36.0-x86_64-pc-windows-msvc.zip".into()),
             download_url: "https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-x86_64-pc-windows-msvc.zip".into(),
@@ -176,6 +189,203 @@ async fn supports_windows_x64() {
     );
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn supports_linux_arm() {
+    let sandbox = create_empty_proto_sandbox();
+    let plugin = sandbox
+        .create_plugin_with_config("just-test", |config| {
+            config.host(HostOS::Linux, HostArch::Arm);
+        })
+        .await;
+
+    assert_eq!(
+        plugin
+            .download_prebuilt(DownloadPrebuiltInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("1.36.0").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await,
+        DownloadPrebuiltOutput {
+            checksum_url: Some("https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-armv7-unknown-linux-musleabihf.tar.gz.sha256sum".into()),
+            download_name: Some("just-1.36.0-armv7-unknown-linux-musleabihf.tar.gz".into()),
+            download_url: "https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-armv7-unknown-linux-musleabihf.tar.gz".into(),
+            ..Default::default()
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn uses_checksum_manifest_for_newer_versions() {
+    let sandbox = create_empty_proto_sandbox();
+    let plugin = sandbox
+        .create_plugin_with_config("just-test", |config| {
+            config.host(HostOS::Linux, HostArch::X64);
+        })
+        .await;
+
+    assert_eq!(
+        plugin
+            .download_prebuilt(DownloadPrebuiltInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("1.37.0").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await,
+        DownloadPrebuiltOutput {
+            checksum_url: Some("https://github.com/casey/just/releases/download/1.37.0/SHA256SUMS".into()),
+            download_name: Some("just-1.37.0-x86_64-unknown-linux-musl.tar.gz".into()),
+            download_url: "https://github.com/casey/just/releases/download/1.37.0/just-1.37.0-x86_64-unknown-linux-musl.tar.gz".into(),
+            ..Default::default()
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn supports_old_linux_x64() {
+    let sandbox = create_empty_proto_sandbox();
+    let plugin = sandbox
+        .create_plugin_with_config("just-test", |config| {
+            config.host(HostOS::Linux, HostArch::X64);
+        })
+        .await;
+
+    assert_eq!(
+        plugin
+            .download_prebuilt(DownloadPrebuiltInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("0.10.0").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await,
+        DownloadPrebuiltOutput {
+            checksum_url: Some("https://github.com/casey/just/releases/download/0.10.0/just-0.10.0-x86_64-unknown-linux-musl.tar.gz.sha256sum".into()),
+            download_name: Some("just-0.10.0-x86_64-unknown-linux-musl.tar.gz".into()),
+            download_url: "https://github.com/casey/just/releases/download/0.10.0/just-0.10.0-x86_64-unknown-linux-musl.tar.gz".into(),
+            ..Default::default()
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[should_panic(expected = "Unable to install Just, unsupported architecture x86 for windows.")]
+async fn doesnt_support_windows_x86() {
+    let sandbox = create_empty_proto_sandbox();
+    let plugin = sandbox
+        .create_plugin_with_config("just-test", |config| {
+            config.host(HostOS::Windows, HostArch::X86);
+        })
+        .await;
+
+    plugin
+        .download_prebuilt(DownloadPrebuiltInput {
+            context: ToolContext {
+                version: VersionSpec::parse("1.36.0").unwrap(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+}
+
+mod target_boundaries {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_linux_arm64_since_first_release() {
+        assert_eq!(
+            download_prebuilt(HostOS::Linux, HostArch::Arm64, "0.8.4")
+                .await
+                .download_name,
+            Some("just-0.8.4-aarch64-unknown-linux-musl.tar.gz".into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "are only available for v0.8.4 and above.")]
+    async fn doesnt_support_linux_arm64_before_first_release() {
+        download_prebuilt(HostOS::Linux, HostArch::Arm64, "0.8.3").await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_linux_arm_since_first_release() {
+        assert_eq!(
+            download_prebuilt(HostOS::Linux, HostArch::Arm, "0.9.0")
+                .await
+                .download_name,
+            Some("just-0.9.0-armv7-unknown-linux-musleabihf.tar.gz".into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "are only available for v0.9.0 and above.")]
+    async fn doesnt_support_linux_arm_before_first_release() {
+        download_prebuilt(HostOS::Linux, HostArch::Arm, "0.8.7").await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_macos_arm64_since_first_release() {
+        assert_eq!(
+            download_prebuilt(HostOS::MacOS, HostArch::Arm64, "0.10.4")
+                .await
+                .download_name,
+            Some("just-0.10.4-aarch64-apple-darwin.tar.gz".into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "are only available for v0.10.4 and above.")]
+    async fn doesnt_support_macos_arm64_before_first_release() {
+        download_prebuilt(HostOS::MacOS, HostArch::Arm64, "0.10.3").await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_windows_arm64_since_first_release() {
+        assert_eq!(
+            download_prebuilt(HostOS::Windows, HostArch::Arm64, "1.29.0")
+                .await
+                .download_name,
+            Some("just-1.29.0-aarch64-pc-windows-msvc.zip".into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "are only available for v1.29.0 and above.")]
+    async fn doesnt_support_windows_arm64_before_first_release() {
+        download_prebuilt(HostOS::Windows, HostArch::Arm64, "1.28.0").await;
+    }
+}
+
+mod checksum_boundaries {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn uses_asset_checksum_before_manifest() {
+        assert_eq!(
+            download_prebuilt(HostOS::Linux, HostArch::X64, "1.36.0")
+                .await
+                .checksum_url,
+            Some("https://github.com/casey/just/releases/download/1.36.0/just-1.36.0-x86_64-unknown-linux-musl.tar.gz.sha256sum".into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn uses_manifest_since_first_release() {
+        assert_eq!(
+            download_prebuilt(HostOS::Windows, HostArch::X64, "1.37.0")
+                .await
+                .checksum_url,
+            Some("https://github.com/casey/just/releases/download/1.37.0/SHA256SUMS".into())
+        );
+    }
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn locates_unix_bin() {
     let sandbox = create_empty_proto_sandbox();
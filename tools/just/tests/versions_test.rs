@@ -26,3 +26,70 @@ async fn sets_latest_alias() {
     assert!(output.aliases.contains_key("latest"));
     assert_eq!(output.aliases.get("latest"), output.latest.as_ref());
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn parses_just_version() {
+    let sandbox = create_empty_proto_sandbox();
+    let plugin = sandbox.create_plugin("just-test").await;
+
+    assert_eq!(
+        plugin
+            .parse_version_file(ParseVersionFileInput {
+                content: "1.36.0\n".into(),
+                file: ".just-version".into(),
+                ..Default::default()
+            })
+            .await,
+        ParseVersionFileOutput {
+            version: Some(UnresolvedVersionSpec::parse("1.36.0").unwrap()),
+        }
+    );
+    assert_eq!(
+        plugin
+            .parse_version_file(ParseVersionFileInput {
+                content: "just 1.36.0 # pinned for CI\n".into(),
+                file: ".tool-versions".into(),
+                ..Default::default()
+            })
+            .await,
+        ParseVersionFileOutput {
+            version: Some(UnresolvedVersionSpec::parse("1.36.0").unwrap()),
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn parses_justfile_header() {
+    let sandbox = create_empty_proto_sandbox();
+    let plugin = sandbox.create_plugin("just-test").await;
+
+    assert_eq!(
+        plugin
+            .parse_version_file(ParseVersionFileInput {
+                content: "# just >= 1.25.0\n\nbuild:\n    cargo build\n".into(),
+                file: "justfile".into(),
+                ..Default::default()
+            })
+            .await,
+        ParseVersionFileOutput {
+            version: Some(UnresolvedVersionSpec::parse(">=1.25.0").unwrap()),
+        }
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn ignores_justfile_without_header() {
+    let sandbox = create_empty_proto_sandbox();
+    let plugin = sandbox.create_plugin("just-test").await;
+
+    assert_eq!(
+        plugin
+            .parse_version_file(ParseVersionFileInput {
+                content: "build:\n    cargo build\n".into(),
+                file: "Justfile".into(),
+                ..Default::default()
+            })
+            .await,
+        ParseVersionFileOutput { version: None }
+    );
+}
@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+// The canary tag is re-published for every build, so each build is pinned
+// to a pre-release version derived from its publish timestamp:
+//   2025-01-02T03:04:05Z -> 0.0.0-canary.20250102030405
+static CANARY_PREFIX: &str = "0.0.0-canary.";
+
+pub fn format_canary_version(published_at: &str) -> Option<String> {
+    let build = published_at
+        .trim_end_matches('Z')
+        .chars()
+        .filter(|c| !matches!(c, '-' | ':' | 'T'))
+        .collect::<String>();
+
+    if build.len() != 14 || !build.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(format!("{CANARY_PREFIX}{build}"))
+}
+
+pub fn parse_canary_build(version: &str) -> Option<&str> {
+    version.strip_prefix(CANARY_PREFIX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_publish_timestamp() {
+        assert_eq!(
+            format_canary_version("2025-01-02T03:04:05Z"),
+            Some("0.0.0-canary.20250102030405".into())
+        );
+    }
+
+    #[test]
+    fn ignores_invalid_timestamps() {
+        assert_eq!(format_canary_version(""), None);
+        assert_eq!(format_canary_version("2025-01-02"), None);
+        assert_eq!(format_canary_version("2025-01-02T03:04:05.123Z"), None);
+    }
+
+    #[test]
+    fn parses_builds() {
+        assert_eq!(
+            parse_canary_build("0.0.0-canary.20250102030405"),
+            Some("20250102030405")
+        );
+        assert_eq!(parse_canary_build("1.30.0"), None);
+        assert_eq!(parse_canary_build("2.0.0-rc.1"), None);
+    }
+}
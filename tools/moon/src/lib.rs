@@ -1,3 +1,6 @@
+mod canary;
+mod workspace;
+
 #[cfg(feature = "wasm")]
 mod proto;
 
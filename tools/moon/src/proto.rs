@@ -1,6 +1,10 @@
+use crate::canary::{format_canary_version, parse_canary_build};
+use crate::workspace::parse_version_constraint;
 use extism_pdk::*;
 use proto_pdk::*;
+use serde::Deserialize;
 use std::collections::HashMap;
+use tool_common::{get_archive_file, get_rust_target, TargetLibc, UnixArchive, MOON_PLATFORMS};
 
 #[host_fn]
 extern "ExtismHost" {
@@ -19,6 +23,29 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
     }))
 }
 
+#[plugin_fn]
+pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
+    Ok(Json(DetectVersionOutput {
+        files: vec![".moon/workspace.yml".into()],
+        ignore: vec![],
+    }))
+}
+
+#[plugin_fn]
+pub fn parse_version_file(
+    Json(input): Json<ParseVersionFileInput>,
+) -> FnResult<Json<ParseVersionFileOutput>> {
+    let mut version = None;
+
+    if input.file.ends_with("workspace.yml") {
+        if let Some(constraint) = parse_version_constraint(&input.content) {
+            version = Some(UnresolvedVersionSpec::parse(constraint)?);
+        }
+    }
+
+    Ok(Json(ParseVersionFileOutput { version }))
+}
+
 #[plugin_fn]
 pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVersionsOutput>> {
     let tags = load_git_tags("https://github.com/moonrepo/moon")?
@@ -29,6 +56,35 @@ pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVers
     Ok(Json(LoadVersionsOutput::from(tags)?))
 }
 
+// Pin canary to the current build, so that it can be identified
+// (and re-installed) after the canary tag has been re-published
+#[plugin_fn]
+pub fn resolve_version(
+    Json(input): Json<ResolveVersionInput>,
+) -> FnResult<Json<ResolveVersionOutput>> {
+    let mut output = ResolveVersionOutput::default();
+
+    if let UnresolvedVersionSpec::Canary = input.initial {
+        let release = load_canary_release()?;
+
+        let Some(version) = format_canary_version(&release.published_at) else {
+            return Err(plugin_err!(
+                "Unable to pin the moon canary build, invalid publish date <id>{}</id>.",
+                release.published_at
+            ));
+        };
+
+        debug!(
+            "Pinned moon canary to {version} (commit {})",
+            release.target_commitish
+        );
+
+        output.version = Some(VersionSpec::parse(version)?);
+    }
+
+    Ok(Json(output))
+}
+
 #[plugin_fn]
 pub fn build_instructions(
     Json(input): Json<BuildInstructionsInput>,
@@ -36,15 +92,7 @@ pub fn build_instructions(
     let env = get_host_environment()?;
     let version = input.context.version;
 
-    check_supported_os_and_arch(
-        "moon",
-        &env,
-        permutations! [
-            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            HostOS::Windows => [HostArch::X64],
-        ],
-    )?;
+    check_supported_os_and_arch("moon", &env, MOON_PLATFORMS.to_permutations())?;
 
     let output = BuildInstructionsOutput {
         source: Some(SourceLocation::Archive(ArchiveSource {
@@ -71,60 +119,155 @@ pub fn build_instructions(
     Ok(Json(output))
 }
 
+#[derive(Deserialize)]
+struct GitHubReleaseAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+#[derive(Deserialize)]
+struct GitHubRelease {
+    assets: Vec<GitHubReleaseAsset>,
+    published_at: String,
+    target_commitish: String,
+}
+
+fn load_canary_release() -> AnyResult<GitHubRelease> {
+    fetch_json("https://api.github.com/repos/moonrepo/moon/releases/tags/canary")
+}
+
+// Starting with v2, releases are archives that contain both
+// the moon and moonx binaries, with a checksum for each archive.
+// Older releases published the moon binary as-is. Pre-releases
+// (2.0.0-rc.1) sort below 2.0.0, so compare the major version.
+fn is_archive_release(version: &VersionSpec) -> bool {
+    version.as_version().is_some_and(|v| v.major >= 2)
+}
+
 #[plugin_fn]
 pub fn download_prebuilt(
     Json(input): Json<DownloadPrebuiltInput>,
 ) -> FnResult<Json<DownloadPrebuiltOutput>> {
     let env = get_host_environment()?;
 
-    check_supported_os_and_arch(
-        "moon",
-        &env,
-        permutations! [
-            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            HostOS::Windows => [HostArch::X64],
-        ],
-    )?;
+    check_supported_os_and_arch("moon", &env, MOON_PLATFORMS.to_permutations())?;
 
     let version = input.context.version;
-    let arch = env.arch.to_rust_arch();
 
-    let tag = if version.is_canary() {
-        "canary".to_owned()
-    } else {
-        format!("v{version}")
+    let Some(target) = get_rust_target(&env, TargetLibc::Host) else {
+        unreachable!();
     };
+    let archive_name = format!("moon_cli-{target}");
+    let archive_file = get_archive_file(&archive_name, env.os, UnixArchive::TarXz);
+    let binary_file = env.os.get_exe_name(format!("moon-{target}"));
 
-    let target = match env.os {
-        HostOS::Linux => format!("{arch}-unknown-linux-{}", env.libc),
-        HostOS::MacOS => format!("{arch}-apple-darwin"),
-        HostOS::Windows => format!("{arch}-pc-windows-msvc"),
-        _ => unreachable!(),
-    };
-    let target_name = format!("moon-{target}");
+    let version_string = version.to_string();
+    let canary_build = parse_canary_build(&version_string);
 
-    let download_file = if env.os.is_windows() {
-        format!("{target_name}.exe")
-    } else {
-        target_name
-    };
-    let base_url = format!("https://github.com/moonrepo/moon/releases/download/{tag}");
+    // The canary tag is re-published for every build, so use the release
+    // metadata to find the assets (and their checksum) of the current build
+    if version.is_canary() || canary_build.is_some() {
+        let release = load_canary_release()?;
+
+        if let Some(build) = canary_build {
+            if format_canary_version(&release.published_at).as_ref() != Some(&version_string) {
+                return Err(plugin_err!(
+                    "The moon canary build <hash>{build}</hash> is no longer available, as the canary tag has been re-published. Install <id>canary</id> again to pin the latest build."
+                ));
+            }
+        }
+
+        let find_asset = |name: &str| release.assets.iter().find(|asset| asset.name == name);
+
+        if let Some(asset) = find_asset(&archive_file) {
+            let checksum = find_asset(&format!("{archive_file}.sha256"));
+
+            return Ok(Json(DownloadPrebuiltOutput {
+                archive_prefix: Some(archive_name),
+                checksum_name: checksum.map(|asset| asset.name.clone()),
+                checksum_url: checksum.map(|asset| asset.browser_download_url.clone()),
+                download_name: Some(asset.name.clone()),
+                download_url: asset.browser_download_url.clone(),
+                ..DownloadPrebuiltOutput::default()
+            }));
+        }
+
+        let Some(asset) = find_asset(&binary_file) else {
+            return Err(plugin_err!(
+                "Unable to find a canary build of moon for <id>{target}</id>."
+            ));
+        };
+
+        let checksum = find_asset(&format!("{binary_file}.sha256"));
+
+        return Ok(Json(DownloadPrebuiltOutput {
+            checksum_name: checksum.map(|asset| asset.name.clone()),
+            checksum_url: checksum.map(|asset| asset.browser_download_url.clone()),
+            download_name: Some(asset.name.clone()),
+            download_url: asset.browser_download_url.clone(),
+            ..DownloadPrebuiltOutput::default()
+        }));
+    }
+
+    let base_url = format!("https://github.com/moonrepo/moon/releases/download/v{version}");
+
+    if is_archive_release(&version) {
+        let checksum_file = format!("{archive_file}.sha256");
+
+        return Ok(Json(DownloadPrebuiltOutput {
+            archive_prefix: Some(archive_name),
+            checksum_url: Some(format!("{base_url}/{checksum_file}")),
+            checksum_name: Some(checksum_file),
+            download_url: format!("{base_url}/{archive_file}"),
+            download_name: Some(archive_file),
+            ..DownloadPrebuiltOutput::default()
+        }));
+    }
 
     Ok(Json(DownloadPrebuiltOutput {
-        download_url: format!("{base_url}/{download_file}"),
-        download_name: Some(download_file),
+        download_url: format!("{base_url}/{binary_file}"),
+        download_name: Some(binary_file),
         ..DownloadPrebuiltOutput::default()
     }))
 }
 
 #[plugin_fn]
 pub fn locate_executables(
-    Json(_): Json<LocateExecutablesInput>,
+    Json(input): Json<LocateExecutablesInput>,
 ) -> FnResult<Json<LocateExecutablesOutput>> {
     let env = get_host_environment()?;
+    let version = &input.context.version;
+
+    // Canary (and pinned canary) and aliased versions may be either
+    // release layout, so check which binaries were actually unpacked
+    let is_archive =
+        if version.as_version().is_some() && parse_canary_build(&version.to_string()).is_none() {
+            is_archive_release(version)
+        } else {
+            input
+                .context
+                .tool_dir
+                .join(env.os.get_exe_name("moonx"))
+                .exists()
+        };
+
+    if is_archive {
+        return Ok(Json(LocateExecutablesOutput {
+            exes: HashMap::from_iter([
+                (
+                    "moon".into(),
+                    ExecutableConfig::new_primary(env.os.get_exe_name("moon")),
+                ),
+                (
+                    "moonx".into(),
+                    ExecutableConfig::new(env.os.get_exe_name("moonx")),
+                ),
+            ]),
+            ..LocateExecutablesOutput::default()
+        }));
+    }
 
-    // Because moon releases do not package the binaries in archives,
+    // Because older moon releases do not package the binaries in archives,
     // the downloaded file gets renamed to the plugin ID, and not just "moon".
     let id = get_plugin_id()?;
 
@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+// moon pins its own version with a top-level setting in .moon/workspace.yml:
+//   versionConstraint: '>=1.30.0'
+// https://moonrepo.dev/docs/config/workspace#versionconstraint
+pub fn parse_version_constraint(content: &str) -> Option<String> {
+    for line in content.lines() {
+        // Only top-level keys, nested settings are indented
+        let Some(value) = line.strip_prefix("versionConstraint:") else {
+            continue;
+        };
+
+        let value = value.trim();
+
+        let value = if let Some(quoted) = value.strip_prefix(['"', '\'']) {
+            quoted.split(['"', '\'']).next().unwrap_or_default()
+        } else {
+            value.split(" #").next().unwrap_or_default().trim()
+        };
+
+        return if value.is_empty() || value == "null" || value == "~" {
+            None
+        } else {
+            Some(value.to_owned())
+        };
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_constraints() {
+        assert_eq!(
+            parse_version_constraint("projects:\n  - 'apps/*'\n\nversionConstraint: '>=1.30.0'\n"),
+            Some(">=1.30.0".into())
+        );
+        assert_eq!(
+            parse_version_constraint("versionConstraint: \"^1.31\" # pinned"),
+            Some("^1.31".into())
+        );
+        assert_eq!(
+            parse_version_constraint("versionConstraint: ~1.31.0 # pinned"),
+            Some("~1.31.0".into())
+        );
+    }
+
+    #[test]
+    fn ignores_missing_constraints() {
+        assert_eq!(parse_version_constraint("projects:\n  - 'apps/*'\n"), None);
+        assert_eq!(parse_version_constraint("versionConstraint: null"), None);
+        assert_eq!(parse_version_constraint("versionConstraint:"), None);
+        assert_eq!(
+            parse_version_constraint("vcs:\n  versionConstraint: '1.0.0'\n"),
+            None
+        );
+    }
+}
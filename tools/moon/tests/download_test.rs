@@ -9,6 +9,49 @@ mod moon_tool {
         use super::*;
 
         generate_download_install_tests!("moon-test", "canary");
+
+        #[tokio::test(flavor = "multi_thread")]
+        #[should_panic(expected = "is no longer available")]
+        async fn errors_for_republished_build() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox.create_plugin("moon-test").await;
+
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("0.0.0-canary.20200101000000").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await;
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn locates_pinned_build_by_layout() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("moon-test", |config| {
+                    config.host(HostOS::Linux, HostArch::Arm64);
+                })
+                .await;
+
+            assert_eq!(
+                plugin
+                    .locate_executables(LocateExecutablesInput {
+                        context: ToolContext {
+                            version: VersionSpec::parse("0.0.0-canary.20250102030405").unwrap(),
+                            ..Default::default()
+                        },
+                    })
+                    .await
+                    .exes
+                    .get("moon")
+                    .unwrap()
+                    .exe_path,
+                Some("moon-test".into())
+            );
+        }
     }
 
     #[tokio::test(flavor = "multi_thread")]
@@ -180,6 +223,105 @@ mod moon_tool {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_archives_with_checksums() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("moon-test", |config| {
+                config.host(HostOS::Linux, HostArch::X64);
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("2.0.0").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("moon_cli-x86_64-unknown-linux-gnu".into()),
+                checksum_name: Some("moon_cli-x86_64-unknown-linux-gnu.tar.xz.sha256".into()),
+                checksum_url: Some(
+                    "https://github.com/moonrepo/moon/releases/download/v2.0.0/moon_cli-x86_64-unknown-linux-gnu.tar.xz.sha256".into()
+                ),
+                download_name: Some("moon_cli-x86_64-unknown-linux-gnu.tar.xz".into()),
+                download_url:
+                    "https://github.com/moonrepo/moon/releases/download/v2.0.0/moon_cli-x86_64-unknown-linux-gnu.tar.xz".into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_prerelease_archives() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("moon-test", |config| {
+                config.host(HostOS::Linux, HostArch::X64);
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("2.0.0-rc.1").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("moon_cli-x86_64-unknown-linux-gnu".into()),
+                checksum_name: Some("moon_cli-x86_64-unknown-linux-gnu.tar.xz.sha256".into()),
+                checksum_url: Some(
+                    "https://github.com/moonrepo/moon/releases/download/v2.0.0-rc.1/moon_cli-x86_64-unknown-linux-gnu.tar.xz.sha256".into()
+                ),
+                download_name: Some("moon_cli-x86_64-unknown-linux-gnu.tar.xz".into()),
+                download_url:
+                    "https://github.com/moonrepo/moon/releases/download/v2.0.0-rc.1/moon_cli-x86_64-unknown-linux-gnu.tar.xz".into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_windows_archives() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("moon-test", |config| {
+                config.host(HostOS::Windows, HostArch::X64);
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("2.0.0").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("moon_cli-x86_64-pc-windows-msvc".into()),
+                checksum_name: Some("moon_cli-x86_64-pc-windows-msvc.zip.sha256".into()),
+                checksum_url: Some(
+                    "https://github.com/moonrepo/moon/releases/download/v2.0.0/moon_cli-x86_64-pc-windows-msvc.zip.sha256".into()
+                ),
+                download_name: Some("moon_cli-x86_64-pc-windows-msvc.zip".into()),
+                download_url:
+                    "https://github.com/moonrepo/moon/releases/download/v2.0.0/moon_cli-x86_64-pc-windows-msvc.zip".into(),
+                ..Default::default()
+            }
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn locates_unix_bin() {
         let sandbox = create_empty_proto_sandbox();
@@ -231,4 +373,32 @@ mod moon_tool {
             Some("moon-test.exe".into())
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn locates_archive_bins() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("moon-test", |config| {
+                config.host(HostOS::Linux, HostArch::Arm64);
+            })
+            .await;
+
+        let output = plugin
+            .locate_executables(LocateExecutablesInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("2.0.0").unwrap(),
+                    ..Default::default()
+                },
+            })
+            .await;
+
+        assert_eq!(
+            output.exes.get("moon").unwrap().exe_path,
+            Some("moon".into())
+        );
+        assert_eq!(
+            output.exes.get("moonx").unwrap().exe_path,
+            Some("moonx".into())
+        );
+    }
 }
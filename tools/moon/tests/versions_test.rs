@@ -30,4 +30,72 @@ mod moon_tool {
         assert!(output.aliases.contains_key("latest"));
         assert_eq!(output.aliases.get("latest"), output.latest.as_ref());
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn pins_canary_to_current_build() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("moon-test").await;
+
+        let output = plugin
+            .resolve_version(ResolveVersionInput {
+                initial: UnresolvedVersionSpec::Canary,
+            })
+            .await;
+
+        assert!(output
+            .version
+            .unwrap()
+            .to_string()
+            .starts_with("0.0.0-canary."));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn doesnt_resolve_other_versions() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("moon-test").await;
+
+        let output = plugin
+            .resolve_version(ResolveVersionInput {
+                initial: UnresolvedVersionSpec::parse("1.30.0").unwrap(),
+            })
+            .await;
+
+        assert_eq!(output.version, None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_workspace_version_constraint() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("moon-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "projects:\n  - 'apps/*'\n\nversionConstraint: '>=1.30.0'\n".into(),
+                    file: ".moon/workspace.yml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse(">=1.30.0").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ignores_workspace_without_constraint() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("moon-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "projects:\n  - 'apps/*'\n".into(),
+                    file: ".moon/workspace.yml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput { version: None }
+        );
+    }
 }
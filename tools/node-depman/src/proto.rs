@@ -2,7 +2,7 @@ use crate::config::NodeDepmanPluginConfig;
 use crate::npm_registry::parse_registry_response;
 use crate::package_manager::PackageManager;
 use extism_pdk::*;
-use node_common::{NodeDistVersion, VoltaField};
+use node_common::{parse_package_manager_field, NodeDistVersion, VoltaField};
 use nodejs_package_json::PackageJson;
 use proto_pdk::*;
 use schematic::SchemaBuilder;
@@ -93,22 +93,10 @@ pub fn parse_version_file(
             let manager_name = PackageManager::detect()?.to_string();
 
             if let Some(pm) = &package_json.package_manager {
-                let mut parts = pm.split('@');
-                let name = parts.next().unwrap_or_default();
+                let (name, value) = parse_package_manager_field(pm);
 
                 if name == manager_name {
-                    let value = if let Some(value) = parts.next() {
-                        // Remove corepack build metadata hash
-                        if let Some(index) = value.find('+') {
-                            &value[0..index]
-                        } else {
-                            value
-                        }
-                    } else {
-                        "latest"
-                    };
-
-                    version = Some(UnresolvedVersionSpec::parse(value)?);
+                    version = Some(UnresolvedVersionSpec::parse(value.unwrap_or("latest"))?);
                 }
             }
 
@@ -5,6 +5,7 @@ use nodejs_package_json::PackageJson;
 use proto_pdk::*;
 use schematic::SchemaBuilder;
 use std::collections::HashMap;
+use tool_common::find_tool_version;
 
 #[host_fn]
 extern "ExtismHost" {
@@ -32,6 +33,7 @@ pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
             ".nvmrc".into(),
             ".node-version".into(),
             "package.json".into(),
+            ".tool-versions".into(),
         ],
         ignore: vec!["node_modules".into()],
     }))
@@ -61,6 +63,8 @@ pub fn parse_version_file(
                 }
             }
         }
+    } else if input.file == ".tool-versions" {
+        version = find_tool_version(&input.content, "node");
     } else {
         for line in input.content.lines() {
             let line = line.trim();
@@ -145,6 +145,18 @@ mod node_tool {
                 version: Some(UnresolvedVersionSpec::parse("~20").unwrap()),
             }
         );
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "# asdf\nnodejs 20.10.0 18.19.0\n".into(),
+                    file: ".tool-versions".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("20.10.0").unwrap()),
+            }
+        );
     }
 
     #[tokio::test(flavor = "multi_thread")]
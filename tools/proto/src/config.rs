@@ -0,0 +1,5 @@
+#[derive(Debug, Default, schematic::Schematic, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ProtoPluginConfig {
+    pub checksum_public_key: Option<String>,
+}
@@ -1,6 +1,9 @@
+use crate::config::ProtoPluginConfig;
 use extism_pdk::*;
 use proto_pdk::*;
+use schematic::SchemaBuilder;
 use std::collections::HashMap;
+use tool_common::{get_archive_file, get_rust_target, TargetLibc, UnixArchive, PROTO_PLATFORMS};
 
 #[host_fn]
 extern "ExtismHost" {
@@ -14,11 +17,44 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
         type_of: PluginType::CommandLine,
         minimum_proto_version: Some(Version::new(0, 42, 0)),
         plugin_version: Version::parse(env!("CARGO_PKG_VERSION")).ok(),
+        config_schema: Some(SchemaBuilder::build_root::<ProtoPluginConfig>()),
         self_upgrade_commands: vec!["up".into(), "upgrade".into()],
         ..ToolMetadataOutput::default()
     }))
 }
 
+#[plugin_fn]
+pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
+    Ok(Json(DetectVersionOutput {
+        files: vec![".proto-version".into()],
+        ignore: vec![],
+    }))
+}
+
+#[plugin_fn]
+pub fn parse_version_file(
+    Json(input): Json<ParseVersionFileInput>,
+) -> FnResult<Json<ParseVersionFileOutput>> {
+    let mut version = None;
+
+    if input.file == ".proto-version" {
+        for line in input.content.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            } else {
+                version = Some(UnresolvedVersionSpec::parse(
+                    line.strip_prefix('v').unwrap_or(line),
+                )?);
+                break;
+            }
+        }
+    }
+
+    Ok(Json(ParseVersionFileOutput { version }))
+}
+
 #[plugin_fn]
 pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVersionsOutput>> {
     let tags = load_git_tags("https://github.com/moonrepo/proto")?
@@ -32,7 +68,26 @@ pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVers
         })
         .collect::<Vec<_>>();
 
-    Ok(Json(LoadVersionsOutput::from(tags)?))
+    let mut output = LoadVersionsOutput::from(tags)?;
+
+    // Stable follows the latest release, while next also
+    // includes pre-releases (alpha, beta, rc) when they are newer
+    if let Some(latest) = &output.latest {
+        output.aliases.insert("stable".into(), latest.clone());
+    }
+
+    if let Some(next) = output
+        .versions
+        .iter()
+        .filter(|version| version.as_version().is_some())
+        .max_by_key(|version| version.as_version())
+    {
+        output
+            .aliases
+            .insert("next".into(), next.to_unresolved_spec());
+    }
+
+    Ok(Json(output))
 }
 
 #[plugin_fn]
@@ -42,15 +97,7 @@ pub fn build_instructions(
     let env = get_host_environment()?;
     let version = input.context.version;
 
-    check_supported_os_and_arch(
-        "proto",
-        &env,
-        permutations! [
-            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            HostOS::Windows => [HostArch::X64],
-        ],
-    )?;
+    check_supported_os_and_arch("proto", &env, PROTO_PLATFORMS.to_permutations())?;
 
     let output = BuildInstructionsOutput {
         source: Some(SourceLocation::Archive(ArchiveSource {
@@ -97,42 +144,38 @@ pub fn download_prebuilt(
 ) -> FnResult<Json<DownloadPrebuiltOutput>> {
     let env = get_host_environment()?;
 
-    check_supported_os_and_arch(
-        "proto",
-        &env,
-        permutations! [
-            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            HostOS::Windows => [HostArch::X64],
-        ],
-    )?;
+    check_supported_os_and_arch("proto", &env, PROTO_PLATFORMS.to_permutations())?;
 
     let version = input.context.version;
-    let arch = env.arch.to_rust_arch();
-
-    if version.is_canary() {
-        return Err(plugin_err!(PluginError::UnsupportedCanary {
-            tool: "proto".into()
-        }));
-    }
 
-    let target = match env.os {
-        HostOS::Linux => format!("{arch}-unknown-linux-{}", env.libc),
-        HostOS::MacOS => format!("{arch}-apple-darwin"),
-        HostOS::Windows => format!("{arch}-pc-windows-msvc"),
-        _ => unreachable!(),
+    let Some(target) = get_rust_target(&env, TargetLibc::Host) else {
+        unreachable!();
     };
     let target_name = format!("proto_cli-{target}");
-    let target_ext = if env.os.is_windows() { "zip" } else { "tar.xz" };
 
-    let download_file = format!("{target_name}.{target_ext}");
-    let checksum_file = format!("{download_file}.sha256");
-    let base_url = format!("https://github.com/moonrepo/proto/releases/download/v{version}");
+    let download_file = get_archive_file(&target_name, env.os, UnixArchive::TarXz);
+    let tag = if version.is_canary() {
+        "canary".to_owned()
+    } else {
+        format!("v{version}")
+    };
+    let base_url = format!("https://github.com/moonrepo/proto/releases/download/{tag}");
+
+    // A checksum served from the same release can be replaced alongside the archive,
+    // so when a public key is configured, verify the minisign signature instead.
+    // Releases don't publish signatures yet, so this is opt-in.
+    let checksum_public_key = get_tool_config::<ProtoPluginConfig>()?.checksum_public_key;
+    let checksum_file = if checksum_public_key.is_some() {
+        format!("{download_file}.minisig")
+    } else {
+        format!("{download_file}.sha256")
+    };
 
     Ok(Json(DownloadPrebuiltOutput {
         archive_prefix: Some(target_name),
         checksum_url: Some(format!("{base_url}/{checksum_file}")),
         checksum_name: Some(checksum_file),
+        checksum_public_key,
         download_url: format!("{base_url}/{download_file}"),
         download_name: Some(download_file),
         ..DownloadPrebuiltOutput::default()
@@ -0,0 +1,141 @@
+use minisign_verify::{PublicKey, Signature};
+use proto_pdk_test_utils::*;
+use starbase_sandbox::locate_fixture;
+use std::fs;
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct ProtoPluginConfig {
+    pub checksum_public_key: Option<String>,
+}
+
+mod proto_tool {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn uses_sha256_checksum_by_default() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("proto-test", |config| {
+                config.host(HostOS::Linux, HostArch::X64);
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("0.44.0").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("proto_cli-x86_64-unknown-linux-gnu".into()),
+                checksum_name: Some("proto_cli-x86_64-unknown-linux-gnu.tar.xz.sha256".into()),
+                checksum_url: Some(
+                    "https://github.com/moonrepo/proto/releases/download/v0.44.0/proto_cli-x86_64-unknown-linux-gnu.tar.xz.sha256".into()
+                ),
+                download_name: Some("proto_cli-x86_64-unknown-linux-gnu.tar.xz".into()),
+                download_url:
+                    "https://github.com/moonrepo/proto/releases/download/v0.44.0/proto_cli-x86_64-unknown-linux-gnu.tar.xz".into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn downloads_canary_from_tag() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("proto-test", |config| {
+                config.host(HostOS::Windows, HostArch::X64);
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::Canary,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("proto_cli-x86_64-pc-windows-msvc".into()),
+                checksum_name: Some("proto_cli-x86_64-pc-windows-msvc.zip.sha256".into()),
+                checksum_url: Some(
+                    "https://github.com/moonrepo/proto/releases/download/canary/proto_cli-x86_64-pc-windows-msvc.zip.sha256".into()
+                ),
+                download_name: Some("proto_cli-x86_64-pc-windows-msvc.zip".into()),
+                download_url:
+                    "https://github.com/moonrepo/proto/releases/download/canary/proto_cli-x86_64-pc-windows-msvc.zip".into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn uses_minisign_signature_with_public_key() {
+        // The fixture is signed with a throwaway key, not a release key
+        let fixture = locate_fixture("minisign");
+        let public_key = fs::read_to_string(fixture.join("public-key.txt"))
+            .unwrap()
+            .trim()
+            .to_owned();
+
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("proto-test", |config| {
+                config.host(HostOS::MacOS, HostArch::Arm64);
+                config.tool_config(ProtoPluginConfig {
+                    checksum_public_key: Some(public_key.clone()),
+                });
+            })
+            .await;
+
+        let output = plugin
+            .download_prebuilt(DownloadPrebuiltInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("0.46.0").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await;
+
+        assert_eq!(
+            output,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("proto_cli-aarch64-apple-darwin".into()),
+                checksum_name: Some("proto_cli-aarch64-apple-darwin.tar.xz.minisig".into()),
+                checksum_public_key: Some(public_key),
+                checksum_url: Some(
+                    "https://github.com/moonrepo/proto/releases/download/v0.46.0/proto_cli-aarch64-apple-darwin.tar.xz.minisig".into()
+                ),
+                download_name: Some("proto_cli-aarch64-apple-darwin.tar.xz".into()),
+                download_url:
+                    "https://github.com/moonrepo/proto/releases/download/v0.46.0/proto_cli-aarch64-apple-darwin.tar.xz".into(),
+                ..Default::default()
+            }
+        );
+
+        let key = PublicKey::from_base64(&output.checksum_public_key.unwrap()).unwrap();
+        let signature =
+            Signature::decode(&fs::read_to_string(fixture.join("download.txt.minisig")).unwrap())
+                .unwrap();
+
+        key.verify(
+            &fs::read(fixture.join("download.txt")).unwrap(),
+            &signature,
+            false,
+        )
+        .unwrap();
+
+        // Tampered downloads must not verify
+        assert!(key.verify(b"tampered", &signature, false).is_err());
+    }
+}
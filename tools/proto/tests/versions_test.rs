@@ -0,0 +1,52 @@
+use proto_pdk_test_utils::*;
+
+mod proto_tool {
+    use super::*;
+
+    generate_resolve_versions_tests!("proto-test", {
+        "0.40" => "0.40.4",
+        "0.44.0" => "0.44.0",
+    });
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn loads_versions_from_git() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("proto-test").await;
+
+        let output = plugin.load_versions(LoadVersionsInput::default()).await;
+
+        assert!(!output.versions.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn sets_channel_aliases() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("proto-test").await;
+
+        let output = plugin.load_versions(LoadVersionsInput::default()).await;
+
+        assert!(output.latest.is_some());
+        assert_eq!(output.aliases.get("latest"), output.latest.as_ref());
+        assert_eq!(output.aliases.get("stable"), output.latest.as_ref());
+        assert!(output.aliases.contains_key("next"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_proto_version() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("proto-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "# Used by CI\nv0.44.0\n".into(),
+                    file: ".proto-version".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("0.44.0").unwrap()),
+            }
+        );
+    }
+}
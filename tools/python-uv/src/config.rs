@@ -0,0 +1,7 @@
+#[derive(Debug, Default, schematic::Schematic, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PythonUvPluginConfig {
+    pub shared_python_dir: bool,
+    pub shared_tool_dir: bool,
+    pub use_proto_python: bool,
+}
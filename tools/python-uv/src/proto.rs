@@ -1,10 +1,18 @@
+use crate::config::PythonUvPluginConfig;
 use extism_pdk::*;
 use proto_pdk::*;
+use python_common::{from_pep440_specifier, PyProjectToml, UvToml};
+use schematic::SchemaBuilder;
 use std::collections::HashMap;
+use std::path::PathBuf;
+use tool_common::{
+    find_tool_version, get_archive_file, get_rust_target, TargetLibc, UnixArchive, UV_PLATFORMS,
+};
 
 #[host_fn]
 extern "ExtismHost" {
     fn exec_command(input: Json<ExecCommandInput>) -> Json<ExecCommandOutput>;
+    fn get_env_var(name: String) -> String;
 }
 
 #[plugin_fn]
@@ -12,6 +20,7 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
     Ok(Json(ToolMetadataOutput {
         name: "uv".into(),
         type_of: PluginType::CommandLine,
+        config_schema: Some(SchemaBuilder::build_root::<PythonUvPluginConfig>()),
         minimum_proto_version: Some(Version::new(0, 42, 0)),
         plugin_version: Version::parse(env!("CARGO_PKG_VERSION")).ok(),
         self_upgrade_commands: vec!["self".into()],
@@ -19,6 +28,48 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
     }))
 }
 
+#[plugin_fn]
+pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
+    Ok(Json(DetectVersionOutput {
+        files: vec![
+            "uv.toml".into(),
+            "pyproject.toml".into(),
+            ".tool-versions".into(),
+        ],
+        ignore: vec![],
+    }))
+}
+
+#[plugin_fn]
+pub fn parse_version_file(
+    Json(input): Json<ParseVersionFileInput>,
+) -> FnResult<Json<ParseVersionFileOutput>> {
+    let mut version = None;
+
+    if input.file == ".tool-versions" {
+        version = find_tool_version(&input.content, "uv");
+
+        return Ok(Json(ParseVersionFileOutput { version }));
+    }
+
+    // https://docs.astral.sh/uv/reference/settings/#required-version
+    let required_version = if input.file == "pyproject.toml" {
+        toml::from_str::<PyProjectToml>(&input.content)
+            .ok()
+            .and_then(|pyproject| pyproject.tool.uv.required_version)
+    } else {
+        toml::from_str::<UvToml>(&input.content)
+            .ok()
+            .and_then(|uv| uv.required_version)
+    };
+
+    if let Some(constraint) = required_version.and_then(|value| from_pep440_specifier(&value)) {
+        version = Some(UnresolvedVersionSpec::parse(constraint)?);
+    }
+
+    Ok(Json(ParseVersionFileOutput { version }))
+}
+
 #[plugin_fn]
 pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVersionsOutput>> {
     let tags = load_git_tags("https://github.com/astral-sh/uv")?;
@@ -32,18 +83,9 @@ pub fn download_prebuilt(
 ) -> FnResult<Json<DownloadPrebuiltOutput>> {
     let env = get_host_environment()?;
 
-    check_supported_os_and_arch(
-        "uv",
-        &env,
-        permutations! [
-            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
-            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
-            HostOS::Windows => [HostArch::X64],
-        ],
-    )?;
+    check_supported_os_and_arch("uv", &env, UV_PLATFORMS.to_permutations())?;
 
     let version = input.context.version;
-    let arch = env.arch.to_rust_arch();
 
     if version.is_canary() {
         return Err(plugin_err!(PluginError::UnsupportedCanary {
@@ -51,19 +93,12 @@ pub fn download_prebuilt(
         }));
     }
 
-    let target = match env.os {
-        HostOS::Linux => format!("{arch}-unknown-linux-{}", env.libc),
-        HostOS::MacOS => format!("{arch}-apple-darwin"),
-        HostOS::Windows => format!("{arch}-pc-windows-msvc"),
-        _ => unreachable!(),
+    let Some(target) = get_rust_target(&env, TargetLibc::Host) else {
+        unreachable!();
     };
     let target_name = format!("uv-{target}");
 
-    let download_file = if env.os.is_windows() {
-        format!("{target_name}.zip")
-    } else {
-        format!("{target_name}.tar.gz")
-    };
+    let download_file = get_archive_file(&target_name, env.os, UnixArchive::TarGz);
     let checksum_file = format!("{download_file}.sha256");
     let base_url = format!("https://github.com/astral-sh/uv/releases/download/{version}");
 
@@ -82,6 +117,20 @@ pub fn locate_executables(
     Json(_): Json<LocateExecutablesInput>,
 ) -> FnResult<Json<LocateExecutablesOutput>> {
     let env = get_host_environment()?;
+    let config = get_tool_config::<PythonUvPluginConfig>()?;
+    let mut globals_lookup_dirs = vec![];
+
+    if config.shared_tool_dir {
+        globals_lookup_dirs.push("$PROTO_HOME/tools/uv-tools/bin".into());
+    }
+
+    // https://docs.astral.sh/uv/reference/cli/#uv-tool-dir
+    globals_lookup_dirs.extend([
+        "$UV_TOOL_BIN_DIR".into(),
+        "$XDG_BIN_HOME".into(),
+        "$XDG_DATA_HOME/../bin".into(),
+        "$HOME/.local/bin".into(),
+    ]);
 
     Ok(Json(LocateExecutablesOutput {
         exes: HashMap::from_iter([
@@ -94,13 +143,100 @@ pub fn locate_executables(
                 ExecutableConfig::new(env.os.get_exe_name("uvx")),
             ),
         ]),
-        // https://docs.astral.sh/uv/reference/cli/#uv-tool-dir
-        globals_lookup_dirs: vec![
-            "$UV_TOOL_BIN_DIR".into(),
-            "$XDG_BIN_HOME".into(),
-            "$XDG_DATA_HOME/../bin".into(),
-            "$HOME/.local/bin".into(),
-        ],
+        globals_lookup_dirs,
         ..LocateExecutablesOutput::default()
     }))
 }
+
+fn set_env_var(result: &mut RunHookResult, name: &str, value: PathBuf) {
+    result
+        .env
+        .get_or_insert(HashMap::default())
+        .insert(name.into(), value.to_string_lossy().to_string());
+}
+
+// Only resolve the Python for commands that use an interpreter, as it
+// spawns a proto process, which would slow down `uv self update`, etc
+// https://docs.astral.sh/uv/reference/cli/
+fn uses_python(args: &[String]) -> bool {
+    args.iter()
+        .find(|arg| !arg.starts_with('-'))
+        .is_some_and(|command| {
+            matches!(
+                command.as_str(),
+                "add"
+                    | "build"
+                    | "export"
+                    | "init"
+                    | "lock"
+                    | "pip"
+                    | "remove"
+                    | "run"
+                    | "sync"
+                    | "tool"
+                    | "tree"
+                    | "venv"
+            )
+        })
+}
+
+#[plugin_fn]
+pub fn pre_run(Json(input): Json<RunHook>) -> FnResult<Json<RunHookResult>> {
+    let mut result = RunHookResult::default();
+    let config = get_tool_config::<PythonUvPluginConfig>()?;
+
+    // ~/.proto/tools/uv/<version> -> ~/.proto/tools
+    let Some(tools_dir) = input.context.tool_dir.real_path().and_then(|dir| {
+        dir.parent()
+            .and_then(|dir| dir.parent())
+            .map(|dir| dir.to_path_buf())
+    }) else {
+        return Ok(Json(result));
+    };
+
+    // Shared directories are siblings of the uv inventory, instead of
+    // within it, so that they are not removed when uv is uninstalled
+    let mut vars = vec![];
+
+    // Share managed Pythons across uv versions
+    if config.shared_python_dir {
+        vars.push(("UV_PYTHON_INSTALL_DIR", tools_dir.join("uv-python")));
+    }
+
+    // Share tools across uv versions, and link their
+    // executables to a directory that proto can locate
+    if config.shared_tool_dir {
+        vars.push(("UV_TOOL_DIR", tools_dir.join("uv-tools")));
+        vars.push(("UV_TOOL_BIN_DIR", tools_dir.join("uv-tools/bin")));
+    }
+
+    for (name, value) in vars {
+        // Explicitly configured variables take precedence
+        if host_env!(name).is_none() {
+            set_env_var(&mut result, name, value);
+        }
+    }
+
+    // Use the Python that proto resolved for the current project, instead of
+    // downloading another. The python plugin locates the executable, as it
+    // differs between variants (python3.13t) and implementations (pypy3).
+    if config.use_proto_python
+        && uses_python(&input.passthrough_args)
+        && host_env!("UV_PYTHON").is_none()
+    {
+        match exec_command!(raw, "proto", ["bin", "python"]) {
+            Ok(Json(output)) if output.exit_code == 0 && !output.stdout.trim().is_empty() => {
+                set_env_var(
+                    &mut result,
+                    "UV_PYTHON",
+                    PathBuf::from(output.stdout.trim()),
+                );
+            }
+            _ => {
+                debug!("Unable to locate a proto managed Python, falling back to uv's discovery");
+            }
+        };
+    }
+
+    Ok(Json(result))
+}
@@ -0,0 +1,175 @@
+use proto_pdk_test_utils::*;
+use serial_test::serial;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PythonUvPluginConfig {
+    pub shared_python_dir: bool,
+    pub shared_tool_dir: bool,
+    pub use_proto_python: bool,
+}
+
+mod python_uv_tool {
+    use super::*;
+
+    mod pre_run {
+        use super::*;
+
+        fn create_context() -> ToolContext {
+            ToolContext {
+                tool_dir: VirtualPath::WithReal {
+                    path: PathBuf::from("/proto/tools/uv/0.5.21"),
+                    virtual_prefix: PathBuf::from("/proto"),
+                    real_prefix: PathBuf::from("/.proto"),
+                },
+                ..ToolContext::default()
+            }
+        }
+
+        fn create_path(path: &str) -> String {
+            PathBuf::from("/.proto/tools")
+                .join(path)
+                .to_string_lossy()
+                .to_string()
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        #[serial]
+        async fn does_nothing_if_not_configured() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox.create_plugin("uv-test").await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(result.args, None);
+            assert_eq!(result.env, None);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        #[serial]
+        async fn sets_python_install_dir() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("uv-test", |config| {
+                    config.tool_config(PythonUvPluginConfig {
+                        shared_python_dir: true,
+                        ..Default::default()
+                    });
+                })
+                .await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(
+                result.env,
+                Some(HashMap::from_iter([(
+                    "UV_PYTHON_INSTALL_DIR".into(),
+                    create_path("uv-python")
+                )]))
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        #[serial]
+        async fn sets_tool_dirs() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("uv-test", |config| {
+                    config.tool_config(PythonUvPluginConfig {
+                        shared_tool_dir: true,
+                        ..Default::default()
+                    });
+                })
+                .await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(
+                result.env,
+                Some(HashMap::from_iter([
+                    ("UV_TOOL_DIR".into(), create_path("uv-tools")),
+                    ("UV_TOOL_BIN_DIR".into(), create_path("uv-tools/bin")),
+                ]))
+            );
+        }
+
+        // The `proto` binary is stubbed, as it prints the executable
+        // of the Python version that was resolved for the project
+        #[cfg(unix)]
+        #[tokio::test(flavor = "multi_thread")]
+        #[serial]
+        async fn sets_python_from_proto_bin() {
+            use std::env;
+            use std::os::unix::fs::PermissionsExt;
+
+            let sandbox = create_empty_proto_sandbox();
+            sandbox.create_file(
+                "bin/proto",
+                "#!/bin/sh\necho /.proto/tools/python/3.13.1t/bin/python3.13t\n",
+            );
+
+            let stub = sandbox.path().join("bin/proto");
+            std::fs::set_permissions(&stub, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+            let plugin = sandbox
+                .create_plugin_with_config("uv-test", |config| {
+                    config.tool_config(PythonUvPluginConfig {
+                        use_proto_python: true,
+                        ..Default::default()
+                    });
+                })
+                .await;
+
+            let path = env::var("PATH").unwrap_or_default();
+            env::set_var(
+                "PATH",
+                format!("{}:{path}", sandbox.path().join("bin").display()),
+            );
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    passthrough_args: vec!["--quiet".into(), "sync".into()],
+                    ..RunHook::default()
+                })
+                .await;
+
+            // Commands without an interpreter don't resolve the Python
+            let self_result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    passthrough_args: vec!["self".into(), "update".into()],
+                    ..RunHook::default()
+                })
+                .await;
+
+            env::set_var("PATH", path);
+
+            assert_eq!(
+                result.env,
+                Some(HashMap::from_iter([(
+                    "UV_PYTHON".into(),
+                    "/.proto/tools/python/3.13.1t/bin/python3.13t".into()
+                )]))
+            );
+            assert_eq!(self_result.env, None);
+        }
+    }
+}
@@ -12,6 +12,7 @@ mod python_uv_tool {
 
         assert_eq!(metadata.name, "uv");
         assert_eq!(metadata.self_upgrade_commands, vec!["self"]);
+        assert!(metadata.config_schema.is_some());
         assert_eq!(
             metadata.plugin_version.unwrap().to_string(),
             env!("CARGO_PKG_VERSION")
@@ -29,4 +29,71 @@ mod python_uv_tool {
         assert!(output.aliases.contains_key("latest"));
         assert_eq!(output.aliases.get("latest"), output.latest.as_ref());
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_uv_toml() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("uv-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "required-version = \">=0.5.0\"\nnative-tls = true".into(),
+                    file: "uv.toml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse(">=0.5.0").unwrap()),
+            }
+        );
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "python 3.12.1\nuv latest:0.5\n".into(),
+                    file: ".tool-versions".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("0.5").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_pyproject_toml() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("uv-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "[project]\nrequires-python = \">=3.12\"\n\n[tool.uv]\nrequired-version = \"~=0.5.21\"".into(),
+                    file: "pyproject.toml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("~0.5.21").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ignores_pyproject_toml_without_required_version() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("uv-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "[project]\nrequires-python = \">=3.12\"".into(),
+                    file: "pyproject.toml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput { version: None }
+        );
+    }
 }
@@ -0,0 +1,76 @@
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    PartialEq,
+    schematic::Schematic,
+    serde::Deserialize,
+    serde::Serialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum PythonVariant {
+    #[default]
+    Default,
+    Debug,
+    Freethreaded,
+    FreethreadedDebug,
+}
+
+impl PythonVariant {
+    pub fn from_abi_flags(flags: &str) -> Option<Self> {
+        match flags {
+            "" => Some(Self::Default),
+            "d" => Some(Self::Debug),
+            "t" => Some(Self::Freethreaded),
+            "td" => Some(Self::FreethreadedDebug),
+            _ => None,
+        }
+    }
+
+    pub fn get_config_value(&self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::Debug => "debug",
+            Self::Freethreaded => "freethreaded",
+            Self::FreethreadedDebug => "freethreaded-debug",
+        }
+    }
+
+    // The ABI flags that are appended to executable names: python3.13t
+    pub fn get_abi_flags(&self) -> &'static str {
+        match self {
+            Self::Default => "",
+            Self::Debug => "d",
+            Self::Freethreaded => "t",
+            Self::FreethreadedDebug => "td",
+        }
+    }
+}
+
+#[derive(Debug, schematic::Schematic, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PythonPluginConfig {
+    pub fallback_releases_url: Option<String>,
+    pub per_version_user_base: bool,
+    pub variant: PythonVariant,
+    pub venv_activate: bool,
+    pub venv_create: bool,
+    pub venv_dir: String,
+}
+
+impl Default for PythonPluginConfig {
+    fn default() -> Self {
+        Self {
+            fallback_releases_url: Some(
+                "https://raw.githubusercontent.com/moonrepo/plugins/master/tools/python/releases.json"
+                    .into(),
+            ),
+            per_version_user_base: false,
+            variant: PythonVariant::default(),
+            venv_activate: false,
+            venv_create: false,
+            venv_dir: ".venv".into(),
+        }
+    }
+}
@@ -0,0 +1,242 @@
+#![allow(dead_code)]
+
+use serde::Deserialize;
+
+// Alternative implementations are selected with a version prefix,
+// using the same format as pyenv:
+//   pypy3.10-7.3.15, pypy3.10, pypy
+//   graalpy-24.1.1, graalpy-24, graalpy
+#[derive(Debug, PartialEq)]
+pub enum PythonImplementation<'a> {
+    CPython,
+    PyPy { python: &'a str, version: &'a str },
+    GraalPy { version: &'a str },
+}
+
+impl PythonImplementation<'_> {
+    pub fn is_cpython(&self) -> bool {
+        matches!(self, Self::CPython)
+    }
+}
+
+pub fn parse_implementation(spec: &str) -> PythonImplementation<'_> {
+    if let Some(rest) = spec.strip_prefix("pypy") {
+        let (python, version) = rest.split_once('-').unwrap_or((rest, ""));
+
+        return PythonImplementation::PyPy {
+            python,
+            version: version.trim_start_matches('v'),
+        };
+    }
+
+    if let Some(rest) = spec.strip_prefix("graalpy") {
+        return PythonImplementation::GraalPy {
+            version: rest.trim_start_matches('-'),
+        };
+    }
+
+    PythonImplementation::CPython
+}
+
+// Partial versions match on component boundaries: 3.1 matches 3.1.2 but not 3.10.0
+pub fn matches_version_prefix(version: &str, prefix: &str) -> bool {
+    prefix.is_empty()
+        || version == prefix
+        || version
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.starts_with('.'))
+}
+
+fn parse_version_parts(version: &str) -> Vec<u32> {
+    version
+        .split('.')
+        .map_while(|part| part.parse().ok())
+        .collect()
+}
+
+// https://downloads.python.org/pypy/versions.json
+#[derive(Deserialize)]
+pub struct PyPyFile {
+    pub filename: String,
+    pub arch: String,
+    pub platform: String,
+    pub download_url: String,
+}
+
+#[derive(Deserialize)]
+pub struct PyPyRelease {
+    pub pypy_version: String,
+    pub python_version: String,
+    pub stable: bool,
+    pub files: Vec<PyPyFile>,
+}
+
+impl PyPyRelease {
+    // pypy3.10-7.3.15
+    pub fn get_spec(&self) -> String {
+        let python = parse_version_parts(&self.python_version)
+            .into_iter()
+            .take(2)
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+
+        format!("pypy{python}-{}", self.pypy_version)
+    }
+
+    pub fn get_file(&self, platform: &str, arch: &str) -> Option<&PyPyFile> {
+        self.files
+            .iter()
+            .find(|file| file.platform == platform && file.arch == arch)
+    }
+}
+
+// Find the latest release that matches both the Python and PyPy versions.
+// Unstable releases (betas, nightlies) must be requested explicitly.
+pub fn find_pypy_release<'a>(
+    releases: &'a [PyPyRelease],
+    python: &str,
+    version: &str,
+) -> Option<&'a PyPyRelease> {
+    releases
+        .iter()
+        .filter(|release| {
+            (release.stable || release.pypy_version == version)
+                && matches_version_prefix(&release.python_version, python)
+                && matches_version_prefix(&release.pypy_version, version)
+        })
+        .max_by_key(|release| {
+            (
+                parse_version_parts(&release.pypy_version),
+                parse_version_parts(&release.python_version),
+            )
+        })
+}
+
+// GraalPy is released from git tags in the format of graal-24.1.1
+pub fn find_graalpy_version(tags: &[String], version: &str) -> Option<String> {
+    tags.iter()
+        .filter_map(|tag| tag.strip_prefix("graal-"))
+        // Standalone distributions were first published with 23.1
+        .filter(|tag| parse_version_parts(tag) >= vec![23, 1])
+        .filter(|tag| matches_version_prefix(tag, version))
+        .max_by_key(|tag| parse_version_parts(tag))
+        .map(|tag| tag.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_release(pypy: &str, python: &str, stable: bool) -> PyPyRelease {
+        PyPyRelease {
+            pypy_version: pypy.into(),
+            python_version: python.into(),
+            stable,
+            files: vec![],
+        }
+    }
+
+    #[test]
+    fn parses_implementations() {
+        assert_eq!(
+            parse_implementation("3.12.1"),
+            PythonImplementation::CPython
+        );
+        assert_eq!(
+            parse_implementation("pypy3.10-7.3.15"),
+            PythonImplementation::PyPy {
+                python: "3.10",
+                version: "7.3.15"
+            }
+        );
+        assert_eq!(
+            parse_implementation("pypy3.10-v7.3"),
+            PythonImplementation::PyPy {
+                python: "3.10",
+                version: "7.3"
+            }
+        );
+        assert_eq!(
+            parse_implementation("pypy"),
+            PythonImplementation::PyPy {
+                python: "",
+                version: ""
+            }
+        );
+        assert_eq!(
+            parse_implementation("graalpy-24.1.1"),
+            PythonImplementation::GraalPy { version: "24.1.1" }
+        );
+        assert_eq!(
+            parse_implementation("graalpy"),
+            PythonImplementation::GraalPy { version: "" }
+        );
+    }
+
+    #[test]
+    fn matches_prefixes() {
+        assert!(matches_version_prefix("3.10.13", ""));
+        assert!(matches_version_prefix("3.10.13", "3"));
+        assert!(matches_version_prefix("3.10.13", "3.10"));
+        assert!(matches_version_prefix("3.10.13", "3.10.13"));
+        assert!(!matches_version_prefix("3.10.13", "3.1"));
+        assert!(!matches_version_prefix("3.10.13", "3.10.1"));
+    }
+
+    #[test]
+    fn finds_pypy_releases() {
+        let releases = vec![
+            create_release("nightly", "3.10", false),
+            create_release("7.3.16rc1", "3.10.14", false),
+            create_release("7.3.15", "3.10.13", true),
+            create_release("7.3.15", "3.9.18", true),
+            create_release("7.3.14", "3.10.13", true),
+            create_release("7.3.9", "2.7.18", true),
+        ];
+
+        assert_eq!(
+            find_pypy_release(&releases, "", "").unwrap().get_spec(),
+            "pypy3.10-7.3.15"
+        );
+        assert_eq!(
+            find_pypy_release(&releases, "3.9", "").unwrap().get_spec(),
+            "pypy3.9-7.3.15"
+        );
+        assert_eq!(
+            find_pypy_release(&releases, "3.10", "7.3.14")
+                .unwrap()
+                .get_spec(),
+            "pypy3.10-7.3.14"
+        );
+        assert_eq!(
+            find_pypy_release(&releases, "2.7", "7.3")
+                .unwrap()
+                .get_spec(),
+            "pypy2.7-7.3.9"
+        );
+        assert_eq!(
+            find_pypy_release(&releases, "3.10", "7.3.16rc1")
+                .unwrap()
+                .get_spec(),
+            "pypy3.10-7.3.16rc1"
+        );
+        assert!(find_pypy_release(&releases, "3.11", "").is_none());
+    }
+
+    #[test]
+    fn finds_graalpy_versions() {
+        let tags = vec![
+            "graal-22.3.0".to_owned(),
+            "graal-23.1.0".to_owned(),
+            "graal-24.0.0".to_owned(),
+            "graal-24.1.1".to_owned(),
+            "vm-24.1.1".to_owned(),
+        ];
+
+        assert_eq!(find_graalpy_version(&tags, ""), Some("24.1.1".into()));
+        assert_eq!(find_graalpy_version(&tags, "24.0"), Some("24.0.0".into()));
+        assert_eq!(find_graalpy_version(&tags, "23"), Some("23.1.0".into()));
+        assert_eq!(find_graalpy_version(&tags, "22.3.0"), None);
+    }
+}
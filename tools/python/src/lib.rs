@@ -1,5 +1,9 @@
+mod config;
+mod implementation;
+mod pipfile;
 #[cfg(feature = "wasm")]
 mod proto;
+mod releases;
 mod version;
 
 #[cfg(feature = "wasm")]
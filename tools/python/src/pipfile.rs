@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct RequiresSection {
+    pub python_full_version: Option<String>,
+    pub python_version: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+#[serde(default)]
+pub struct Pipfile {
+    pub requires: RequiresSection,
+}
@@ -1,23 +1,52 @@
-use crate::version::from_python_version;
+use crate::config::{PythonPluginConfig, PythonVariant};
+use crate::implementation::{
+    find_graalpy_version, find_pypy_release, parse_implementation, PyPyRelease,
+    PythonImplementation,
+};
+use crate::pipfile::Pipfile;
+use crate::releases::{lookup_release, parse_checksums_file, GitHubRelease, ReleaseLookup};
+use crate::version::{create_version_regex, from_python_version};
 use extism_pdk::*;
 use proto_pdk::*;
-use regex::Regex;
+use python_common::{from_pep440_specifier, PyProjectToml};
+use schematic::SchemaBuilder;
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
 use std::collections::{BTreeMap, HashMap};
+use std::{fs, io};
+use tool_common::parse_tool_versions;
 
 #[host_fn]
 extern "ExtismHost" {
     fn exec_command(input: Json<ExecCommandInput>) -> Json<ExecCommandOutput>;
+    fn get_env_var(name: String) -> String;
     fn host_log(input: Json<HostLogInput>);
+    fn send_request(input: Json<SendRequestInput>) -> Json<SendRequestOutput>;
+    fn to_virtual_path(input: String) -> String;
 }
 
 static NAME: &str = "Python";
+static PYPY_VERSIONS_URL: &str = "https://downloads.python.org/pypy/versions.json";
+static RELEASES_REPO: &str = "https://github.com/astral-sh/python-build-standalone";
 
 #[plugin_fn]
 pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMetadataOutput>> {
+    let config = get_tool_config::<PythonPluginConfig>()?;
+    let abi_flags = config.variant.get_abi_flags();
+
     Ok(Json(ToolMetadataOutput {
         name: NAME.into(),
         type_of: PluginType::Language,
+        config_schema: Some(SchemaBuilder::build_root::<PythonPluginConfig>()),
+        // Install variants side-by-side with the default builds: 3.13.1t
+        inventory: ToolInventoryMetadata {
+            version_suffix: if abi_flags.is_empty() {
+                None
+            } else {
+                Some(abi_flags.into())
+            },
+            ..ToolInventoryMetadata::default()
+        },
         minimum_proto_version: Some(Version::new(0, 42, 0)),
         plugin_version: Version::parse(env!("CARGO_PKG_VERSION")).ok(),
         ..ToolMetadataOutput::default()
@@ -27,18 +56,119 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
 #[plugin_fn]
 pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
     Ok(Json(DetectVersionOutput {
-        files: vec![".python-version".into()],
+        files: vec![
+            ".python-version".into(),
+            "runtime.txt".into(),
+            "Pipfile".into(),
+            "pyproject.toml".into(),
+            ".tool-versions".into(),
+        ],
         ignore: vec![],
     }))
 }
 
+fn parse_version_line(
+    line: &str,
+    variant: PythonVariant,
+) -> AnyResult<Option<UnresolvedVersionSpec>> {
+    let line = line.trim();
+
+    if line.is_empty() || line.starts_with('#') || line == "system" {
+        return Ok(None);
+    }
+
+    // PyPy and GraalPy are supported (pypy3.10-7.3.15, graalpy-24.1.0),
+    // but other implementations (jython, miniconda, etc) are not
+    if !line.starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok(if parse_implementation(line).is_cpython() {
+            None
+        } else {
+            Some(UnresolvedVersionSpec::Alias(line.into()))
+        });
+    }
+
+    // Free-threaded (3.13t) and debug (3.13d) builds are selected with the
+    // `variant` setting, as variants are installed to their own folder,
+    // so the setting must match, otherwise the wrong build is installed
+    let version = line.trim_end_matches(['t', 'd']);
+    let abi_flags = &line[version.len()..];
+
+    if !abi_flags.is_empty() && abi_flags != variant.get_abi_flags() {
+        return Err(match PythonVariant::from_abi_flags(abi_flags) {
+            Some(wanted) => anyhow!(
+                "Python version <hash>{line}</hash> requires the <property>variant</property> setting to be <id>{}</id>, but it is <id>{}</id>.",
+                wanted.get_config_value(),
+                variant.get_config_value()
+            ),
+            None => anyhow!("Invalid ABI flags <id>{abi_flags}</id> for Python version <hash>{line}</hash>."),
+        });
+    }
+
+    Ok(Some(UnresolvedVersionSpec::parse(version)?))
+}
+
+#[plugin_fn]
+pub fn parse_version_file(
+    Json(input): Json<ParseVersionFileInput>,
+) -> FnResult<Json<ParseVersionFileOutput>> {
+    let variant = get_tool_config::<PythonPluginConfig>()?.variant;
+    let mut version = None;
+
+    if input.file == "pyproject.toml" {
+        if let Ok(pyproject) = toml::from_str::<PyProjectToml>(&input.content) {
+            if let Some(constraint) = pyproject
+                .project
+                .requires_python
+                .or(pyproject.tool.poetry.dependencies.python)
+                .and_then(|value| from_pep440_specifier(&value))
+            {
+                // Skip specifiers that can't be converted, like pre-releases (>=3.9.0b1)
+                version = UnresolvedVersionSpec::parse(constraint).ok();
+            }
+        }
+    } else if input.file == "Pipfile" {
+        if let Ok(pipfile) = toml::from_str::<Pipfile>(&input.content) {
+            if let Some(constraint) = pipfile
+                .requires
+                .python_full_version
+                .or(pipfile.requires.python_version)
+            {
+                version = Some(UnresolvedVersionSpec::parse(constraint)?);
+            }
+        }
+    } else if input.file == "runtime.txt" {
+        // python-3.11.4
+        if let Some(value) = input.content.trim().strip_prefix("python-") {
+            version = parse_version_line(value, variant)?;
+        }
+    } else if input.file == ".tool-versions" {
+        // Multiple fallback versions can be listed, so use the first supported
+        for value in parse_tool_versions(&input.content, "python") {
+            version = parse_version_line(value, variant)?;
+
+            if version.is_some() {
+                break;
+            }
+        }
+    } else {
+        // Multiple versions can be listed on separate lines,
+        // with the first being the primary version
+        for line in input.content.lines() {
+            version = parse_version_line(line, variant)?;
+
+            if version.is_some() {
+                break;
+            }
+        }
+    }
+
+    Ok(Json(ParseVersionFileOutput { version }))
+}
+
 #[plugin_fn]
 pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVersionsOutput>> {
     let tags = load_git_tags("https://github.com/python/cpython")?;
-    let regex = Regex::new(
-        r"v?(?<major>[0-9]+)\.(?<minor>[0-9]+)(?:\.(?<patch>[0-9]+))?(?:(?<pre>a|b|c|rc)(?<preid>[0-9]+))?",
-    )
-    .unwrap();
+    let regex = create_version_regex();
 
     let tags = tags
         .into_iter()
@@ -54,6 +184,41 @@ pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVers
     Ok(Json(LoadVersionsOutput::from(tags)?))
 }
 
+#[plugin_fn]
+pub fn resolve_version(
+    Json(input): Json<ResolveVersionInput>,
+) -> FnResult<Json<ResolveVersionOutput>> {
+    let mut output = ResolveVersionOutput::default();
+
+    let UnresolvedVersionSpec::Alias(alias) = &input.initial else {
+        return Ok(Json(output));
+    };
+
+    let resolved = match parse_implementation(alias) {
+        PythonImplementation::CPython => return Ok(Json(output)),
+        PythonImplementation::PyPy { python, version } => {
+            let releases: Vec<PyPyRelease> = fetch_json(PYPY_VERSIONS_URL)?;
+
+            find_pypy_release(&releases, python, version).map(|release| release.get_spec())
+        }
+        PythonImplementation::GraalPy { version } => {
+            let tags = load_git_tags("https://github.com/oracle/graalpython")?;
+
+            find_graalpy_version(&tags, version).map(|version| format!("graalpy-{version}"))
+        }
+    };
+
+    let Some(resolved) = resolved else {
+        return Err(plugin_err!(
+            "Unable to resolve a Python implementation for <hash>{alias}</hash>."
+        ));
+    };
+
+    output.version = Some(VersionSpec::parse(resolved)?);
+
+    Ok(Json(output))
+}
+
 #[plugin_fn]
 pub fn build_instructions(
     Json(input): Json<BuildInstructionsInput>,
@@ -138,6 +303,226 @@ struct ReleaseEntry {
     checksum: Option<String>,
 }
 
+// Returns the asset names of a release, and the URL of its checksums file
+fn load_release_assets(tag: &str) -> AnyResult<(Vec<String>, Option<String>)> {
+    let checksums_url = format!("{RELEASES_REPO}/releases/download/{tag}/SHA256SUMS");
+    let response = send_request!(checksums_url.as_str());
+
+    if response.status == 200 {
+        return Ok((parse_checksums_file(&response.text()?), Some(checksums_url)));
+    }
+
+    // Older releases don't have a checksums file, so the assets must
+    // be loaded from the API, which is heavily rate limited
+    if response.status == 404 {
+        let release: GitHubRelease = fetch_json(format!(
+            "https://api.github.com/repos/astral-sh/python-build-standalone/releases/tags/{tag}"
+        ))?;
+
+        return Ok((
+            release.assets.into_iter().map(|asset| asset.name).collect(),
+            None,
+        ));
+    }
+
+    Err(anyhow!(
+        "Failed to load python-build-standalone release <hash>{tag}</hash> from <url>{checksums_url}</url> <mutedlight>({})</mutedlight>",
+        response.status
+    ))
+}
+
+fn find_release_from_github(
+    version: &Version,
+    triple: &str,
+    variant: PythonVariant,
+) -> AnyResult<Option<DownloadPrebuiltOutput>> {
+    let regex = create_version_regex();
+    let version = version.to_string();
+
+    // Releases are tagged by date (20250115), and are sorted oldest to newest
+    let tags = load_git_tags(RELEASES_REPO)?
+        .into_iter()
+        .filter(|tag| tag.chars().all(|c| c.is_ascii_digit()))
+        .collect::<Vec<_>>();
+
+    if tags.is_empty() {
+        return Err(anyhow!(
+            "Failed to load python-build-standalone releases from <url>{RELEASES_REPO}</url>."
+        ));
+    }
+
+    // Binary search for the newest release that contains the version,
+    // so that only a handful of releases need to be loaded
+    let mut found = None;
+    let mut low = 0;
+    let mut high = tags.len();
+
+    while low < high {
+        let mid = (low + high) / 2;
+        let tag = &tags[mid];
+        let (names, checksums_url) = load_release_assets(tag)?;
+
+        match lookup_release(&names, tag, &version, triple, variant, &regex) {
+            ReleaseLookup::Found(name, archive_prefix) => {
+                let checksum_name = match &checksums_url {
+                    Some(_) => Some("SHA256SUMS".to_owned()),
+                    // Older releases provide a checksum file per asset
+                    None => Some(format!("{name}.sha256")).filter(|other| names.contains(other)),
+                };
+
+                found = Some(DownloadPrebuiltOutput {
+                    archive_prefix: Some(archive_prefix.into()),
+                    checksum_url: checksums_url.or_else(|| {
+                        checksum_name
+                            .as_ref()
+                            .map(|other| get_release_asset_url(tag, other))
+                    }),
+                    checksum_name,
+                    download_name: Some(name.to_owned()),
+                    download_url: get_release_asset_url(tag, name),
+                    ..DownloadPrebuiltOutput::default()
+                });
+
+                low = mid + 1;
+            }
+            ReleaseLookup::Newer => {
+                low = mid + 1;
+            }
+            ReleaseLookup::Older => {
+                high = mid;
+            }
+            ReleaseLookup::Missing => {
+                break;
+            }
+        };
+    }
+
+    Ok(found)
+}
+
+fn get_release_asset_url(tag: &str, name: &str) -> String {
+    format!(
+        "{RELEASES_REPO}/releases/download/{tag}/{}",
+        name.replace('+', "%2B")
+    )
+}
+
+fn find_release_from_fallback(
+    url: &str,
+    version: &Version,
+    triple: &str,
+) -> AnyResult<Option<DownloadPrebuiltOutput>> {
+    let releases: BTreeMap<Version, BTreeMap<String, ReleaseEntry>> = fetch_json(url)?;
+
+    Ok(releases
+        .get(version)
+        .and_then(|triples| triples.get(triple))
+        .map(|release| DownloadPrebuiltOutput {
+            archive_prefix: Some("python/install".into()),
+            checksum_url: release.checksum.clone(),
+            download_url: release.download.clone(),
+            ..DownloadPrebuiltOutput::default()
+        }))
+}
+
+fn download_pypy(
+    env: &HostEnvironment,
+    python: &str,
+    version: &str,
+) -> AnyResult<DownloadPrebuiltOutput> {
+    check_supported_os_and_arch(
+        NAME,
+        env,
+        permutations! [
+            HostOS::Linux => [HostArch::X86, HostArch::X64, HostArch::Arm64, HostArch::S390x],
+            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
+            HostOS::Windows => [HostArch::X64],
+        ],
+    )?;
+
+    if env.libc == HostLibc::Musl {
+        return Err(anyhow!(
+            "PyPy does not provide pre-builts for musl, only glibc."
+        ));
+    }
+
+    let (platform, arch) = match (env.os, env.arch) {
+        (HostOS::MacOS, HostArch::X64) => ("darwin", "x64"),
+        (HostOS::MacOS, HostArch::Arm64) => ("darwin", "arm64"),
+        (HostOS::Windows, HostArch::X64) => ("win64", "x64"),
+        (HostOS::Linux, HostArch::X86) => ("linux", "i686"),
+        (HostOS::Linux, HostArch::Arm64) => ("linux", "aarch64"),
+        (HostOS::Linux, HostArch::S390x) => ("linux", "s390x"),
+        _ => ("linux", "x64"),
+    };
+
+    let releases: Vec<PyPyRelease> = fetch_json(PYPY_VERSIONS_URL)?;
+
+    let Some(file) = find_pypy_release(&releases, python, version)
+        .and_then(|release| release.get_file(platform, arch))
+    else {
+        return Err(anyhow!(
+            "No pre-built available for PyPy <hash>{version}</hash> (Python {python}) and platform <id>{platform}-{arch}</id> (via <url>{PYPY_VERSIONS_URL}</url>)!"
+        ));
+    };
+
+    let archive_prefix = file
+        .filename
+        .trim_end_matches(".tar.bz2")
+        .trim_end_matches(".zip");
+
+    Ok(DownloadPrebuiltOutput {
+        archive_prefix: Some(archive_prefix.into()),
+        // PyPy only publishes checksums on their website,
+        // which is handled by the `verify_checksum` function
+        checksum_url: Some("https://www.pypy.org/checksums.html".into()),
+        download_name: Some(file.filename.clone()),
+        download_url: file.download_url.clone(),
+        ..DownloadPrebuiltOutput::default()
+    })
+}
+
+fn download_graalpy(env: &HostEnvironment, version: &str) -> AnyResult<DownloadPrebuiltOutput> {
+    check_supported_os_and_arch(
+        NAME,
+        env,
+        permutations! [
+            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
+            HostOS::MacOS => [HostArch::X64, HostArch::Arm64],
+            HostOS::Windows => [HostArch::X64],
+        ],
+    )?;
+
+    let os = match env.os {
+        HostOS::MacOS => "macos",
+        HostOS::Windows => "windows",
+        _ => "linux",
+    };
+    let arch = match env.arch {
+        HostArch::Arm64 => "aarch64",
+        _ => "amd64",
+    };
+    let target_name = format!("graalpy-{version}-{os}-{arch}");
+
+    let download_file = if env.os.is_windows() {
+        format!("{target_name}.zip")
+    } else {
+        format!("{target_name}.tar.gz")
+    };
+    let checksum_file = format!("{download_file}.sha256");
+    let base_url =
+        format!("https://github.com/oracle/graalpython/releases/download/graal-{version}");
+
+    Ok(DownloadPrebuiltOutput {
+        archive_prefix: Some(target_name),
+        checksum_url: Some(format!("{base_url}/{checksum_file}")),
+        checksum_name: Some(checksum_file),
+        download_url: format!("{base_url}/{download_file}"),
+        download_name: Some(download_file),
+        ..DownloadPrebuiltOutput::default()
+    })
+}
+
 #[plugin_fn]
 pub fn download_prebuilt(
     Json(input): Json<DownloadPrebuiltInput>,
@@ -151,42 +536,129 @@ pub fn download_prebuilt(
         }));
     }
 
-    let releases: BTreeMap<Version, BTreeMap<String, ReleaseEntry>> = fetch_json(
-        "https://raw.githubusercontent.com/moonrepo/plugins/master/tools/python/releases.json",
-    )?;
+    match parse_implementation(&version.to_string()) {
+        PythonImplementation::PyPy {
+            python,
+            version: pypy_version,
+        } => {
+            return Ok(Json(download_pypy(&env, python, pypy_version)?));
+        }
+        PythonImplementation::GraalPy {
+            version: graalpy_version,
+        } => {
+            return Ok(Json(download_graalpy(&env, graalpy_version)?));
+        }
+        PythonImplementation::CPython => {}
+    };
 
-    let Some(release_triples) = version.as_version().and_then(|v| releases.get(v)) else {
+    let Some(semver) = version.as_version() else {
         return Err(plugin_err!(
             "No pre-built available for version <hash>{version}</hash> (via <url>https://github.com/astral-sh/python-build-standalone</url>)! Try building from source with <shell>--build</shell>.",
         ));
     };
 
     let triple = get_target_triple(&env, NAME)?;
+    let config = get_tool_config::<PythonPluginConfig>()?;
+
+    let mut output = match find_release_from_github(semver, &triple, config.variant) {
+        Ok(output) => output,
+        // Releases may not be reachable (rate limits, air-gapped networks, etc),
+        // so only continue when a mirror has been configured
+        Err(error) if config.fallback_releases_url.is_some() => {
+            host_log!(
+                stderr,
+                "Failed to load python-build-standalone releases, using the fallback index instead: {}",
+                error
+            );
+
+            None
+        }
+        Err(error) => return Err(error.into()),
+    };
 
-    let Some(release) = release_triples.get(&triple) else {
+    // The fallback index only contains default builds
+    if output.is_none() && config.variant == PythonVariant::Default {
+        if let Some(url) = &config.fallback_releases_url {
+            debug!(
+                "Loading pre-built releases from fallback <url>{}</url>",
+                url
+            );
+
+            output = find_release_from_fallback(url, semver, &triple)?;
+        }
+    }
+
+    let Some(output) = output else {
         return Err(plugin_err!(
-            "No pre-built available for architecture <id>{triple}</id>! Try building from source with <shell>--build</shell>."
+            "No pre-built available for version <hash>{version}</hash> and architecture <id>{triple}</id> (via <url>https://github.com/astral-sh/python-build-standalone</url>)! Try building from source with <shell>--build</shell>."
         ));
     };
 
-    Ok(Json(DownloadPrebuiltOutput {
-        archive_prefix: Some("python/install".into()),
-        checksum_url: release.checksum.clone(),
-        download_url: release.download.clone(),
-        ..DownloadPrebuiltOutput::default()
-    }))
+    Ok(Json(output))
 }
 
 #[plugin_fn]
-pub fn locate_executables(
-    Json(input): Json<LocateExecutablesInput>,
-) -> FnResult<Json<LocateExecutablesOutput>> {
-    let env = get_host_environment()?;
+pub fn verify_checksum(
+    Json(input): Json<VerifyChecksumInput>,
+) -> FnResult<Json<VerifyChecksumOutput>> {
+    let mut file = fs::File::open(&input.download_file)?;
+    let mut sha = Sha256::new();
+
+    io::copy(&mut file, &mut sha)?;
+
+    let checksum = format!("{:x}", sha.finalize());
+    let download_name = input
+        .download_file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    // Checksum files are in the format of "<checksum>  <file>" or "<checksum>",
+    // but PyPy's checksums are embedded in an HTML page, so be more lenient
+    let verified = fs::read_to_string(&input.checksum_file)?
+        .lines()
+        .map(|line| line.trim())
+        .any(|line| {
+            line == checksum || (line.contains(&checksum) && line.contains(&download_name))
+        });
+
+    Ok(Json(VerifyChecksumOutput { verified }))
+}
+
+// Returns the relative executable path and executables directory
+fn get_exe_paths(
+    env: &HostEnvironment,
+    context: &ToolContext,
+    variant: PythonVariant,
+) -> (String, String) {
     let mut exe_path = env.os.for_native("bin/python", "python.exe").to_owned();
     let mut exes_dir = env.os.for_native("bin", "Scripts").to_owned();
 
+    match parse_implementation(&context.version.to_string()) {
+        PythonImplementation::PyPy { python, .. } => {
+            let exe_name = if python.starts_with('2') {
+                "pypy"
+            } else {
+                "pypy3"
+            };
+
+            return (
+                if env.os.is_windows() {
+                    format!("{exe_name}.exe")
+                } else {
+                    format!("bin/{exe_name}")
+                },
+                exes_dir,
+            );
+        }
+        PythonImplementation::GraalPy { .. } => {
+            return (env.os.get_exe_name("bin/graalpy"), exes_dir);
+        }
+        PythonImplementation::CPython => {}
+    };
+
     // Backwards compatibility for the old pre-built implementation
-    if input.context.tool_dir.join("PYTHON.json").exists() {
+    if context.tool_dir.join("PYTHON.json").exists() {
         exe_path = env
             .os
             .for_native("install/bin/python", "install/python.exe")
@@ -197,19 +669,69 @@ pub fn locate_executables(
             .to_owned();
     }
 
+    // Variants append ABI flags to the executable name: python3.13t
+    let abi_flags = variant.get_abi_flags();
+
+    if !abi_flags.is_empty() {
+        if let Some(version) = context.version.as_version() {
+            let exe_dir = exe_path
+                .rsplit_once('/')
+                .map(|(dir, _)| format!("{dir}/"))
+                .unwrap_or_default();
+            let variant_exe_path = env.os.get_exe_name(format!(
+                "{exe_dir}python{}.{}{abi_flags}",
+                version.major, version.minor
+            ));
+
+            if context.tool_dir.join(&variant_exe_path).exists() {
+                exe_path = variant_exe_path;
+            }
+        }
+    }
+
     // When on Unix, the executable returned from `PYTHON.json` is `pythonX.X`,
     // but this causes issues with our bin linking strategy, as the version in the
     // file name can be different than the one resolved, resulting in invalid
     // symlinks. To work around this, we can use `pythonX` instead, if `python`
     // itself doesn't exist (which is true for some versions).
-    if !env.os.is_windows() && !input.context.tool_dir.join(&exe_path).exists() {
-        if let Some(version) = input.context.version.as_version() {
+    if !env.os.is_windows() && !context.tool_dir.join(&exe_path).exists() {
+        if let Some(version) = context.version.as_version() {
             exe_path = format!("{exe_path}{}", version.major);
         }
     }
 
+    (exe_path, exes_dir)
+}
+
+// https://docs.python.org/3/library/site.html#site.USER_BASE
+fn get_user_base_bin_dir(env: &HostEnvironment, context: &ToolContext) -> String {
+    match (env.os.is_windows(), context.version.as_version()) {
+        (true, Some(version)) => format!("user/Python{}{}/Scripts", version.major, version.minor),
+        _ => "user/bin".into(),
+    }
+}
+
+#[plugin_fn]
+pub fn locate_executables(
+    Json(input): Json<LocateExecutablesInput>,
+) -> FnResult<Json<LocateExecutablesOutput>> {
+    let env = get_host_environment()?;
+    let config = get_tool_config::<PythonPluginConfig>()?;
+    let (exe_path, exes_dir) = get_exe_paths(&env, &input.context, config.variant);
+
+    let mut globals_lookup_dirs = vec![format!("$TOOL_DIR/{exes_dir}")];
+
+    if config.per_version_user_base {
+        globals_lookup_dirs.push(format!(
+            "$TOOL_DIR/{}",
+            get_user_base_bin_dir(&env, &input.context)
+        ));
+    } else {
+        globals_lookup_dirs.push("$HOME/.local/bin".into());
+    }
+
     Ok(Json(LocateExecutablesOutput {
-        globals_lookup_dirs: vec![format!("$TOOL_DIR/{exes_dir}"), "$HOME/.local/bin".into()],
+        globals_lookup_dirs,
         exes: HashMap::from_iter([
             ("python".into(), ExecutableConfig::new_primary(exe_path)),
             (
@@ -225,3 +747,79 @@ pub fn locate_executables(
         ..LocateExecutablesOutput::default()
     }))
 }
+
+#[plugin_fn]
+pub fn pre_run(Json(input): Json<RunHook>) -> FnResult<Json<RunHookResult>> {
+    let mut result = RunHookResult::default();
+    let config = get_tool_config::<PythonPluginConfig>()?;
+
+    if !config.per_version_user_base && !config.venv_activate {
+        return Ok(Json(result));
+    }
+
+    let env = get_host_environment()?;
+
+    // Redirect `pip install --user` (and the user site-packages)
+    // to a directory within the current version's tool directory
+    if config.per_version_user_base {
+        if let Some(user_base) = input.context.tool_dir.join("user").real_path() {
+            result.env.get_or_insert(HashMap::default()).insert(
+                "PYTHONUSERBASE".into(),
+                user_base.to_string_lossy().to_string(),
+            );
+        }
+    }
+
+    // Respect a virtual environment that has already been activated
+    if !config.venv_activate || host_env!("VIRTUAL_ENV").is_some_and(|value| !value.is_empty()) {
+        return Ok(Json(result));
+    }
+
+    let venv_dir = virtual_path!("/cwd").join(&config.venv_dir);
+
+    // Only create a virtual environment for projects,
+    // otherwise we'd litter every directory that Python is ran in
+    if config.venv_create
+        && !venv_dir.exists()
+        && ["pyproject.toml", "requirements.txt", "setup.py"]
+            .iter()
+            .any(|file| virtual_path!("/cwd").join(file).exists())
+    {
+        let (exe_path, _) = get_exe_paths(&env, &input.context, config.variant);
+
+        if let (Some(python), Some(dir)) = (
+            input.context.tool_dir.join(exe_path).real_path(),
+            venv_dir.real_path(),
+        ) {
+            debug!(
+                "Creating virtual environment <path>{}</path>",
+                dir.display()
+            );
+
+            let python = python.to_string_lossy().to_string();
+            let dir = dir.to_string_lossy().to_string();
+
+            exec_command!(inherit, python, ["-m", "venv", dir.as_str()]);
+        }
+    }
+
+    if venv_dir.join("pyvenv.cfg").exists() {
+        if let Some(dir) = venv_dir.real_path() {
+            let vars = result.env.get_or_insert(HashMap::default());
+            let bin_dir = dir.join(env.os.for_native("bin", "Scripts"));
+
+            // Variables overwrite the existing ones, so prepend to the host's `PATH`
+            let mut path = bin_dir.to_string_lossy().to_string();
+
+            if let Some(host_path) = host_env!("PATH") {
+                path.push_str(env.os.for_native(":", ";"));
+                path.push_str(&host_path);
+            }
+
+            vars.insert("VIRTUAL_ENV".into(), dir.to_string_lossy().to_string());
+            vars.insert("PATH".into(), path);
+        }
+    }
+
+    Ok(Json(result))
+}
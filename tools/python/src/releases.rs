@@ -0,0 +1,503 @@
+use crate::config::PythonVariant;
+use crate::version::from_python_version;
+use regex::Regex;
+use serde::Deserialize;
+use std::cmp::Ordering;
+
+// https://docs.github.com/en/rest/releases/releases#get-a-release-by-tag-name
+#[derive(Deserialize)]
+pub struct GitHubReleaseAsset {
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+pub struct GitHubRelease {
+    pub assets: Vec<GitHubReleaseAsset>,
+}
+
+// Flavors in order of most wanted to least wanted. The "install only"
+// archives are smaller, but were not published for older releases.
+// https://gregoryszorc.com/docs/python-build-standalone/main/distributions.html
+static FLAVORS: [(&str, &str); 6] = [
+    ("install_only_stripped.tar.gz", "python"),
+    ("install_only.tar.gz", "python"),
+    ("pgo+lto-full.tar.zst", "python/install"),
+    ("pgo-full.tar.zst", "python/install"),
+    ("lto-full.tar.zst", "python/install"),
+    ("noopt-full.tar.zst", "python/install"),
+];
+
+// Variants are only published as full archives.
+static DEBUG_FLAVORS: [(&str, &str); 1] = [("debug-full.tar.zst", "python/install")];
+
+static FREETHREADED_FLAVORS: [(&str, &str); 4] = [
+    ("freethreaded+pgo+lto-full.tar.zst", "python/install"),
+    ("freethreaded+pgo-full.tar.zst", "python/install"),
+    ("freethreaded+lto-full.tar.zst", "python/install"),
+    ("freethreaded+noopt-full.tar.zst", "python/install"),
+];
+
+static FREETHREADED_DEBUG_FLAVORS: [(&str, &str); 1] =
+    [("freethreaded+debug-full.tar.zst", "python/install")];
+
+pub fn get_flavors(variant: PythonVariant) -> &'static [(&'static str, &'static str)] {
+    match variant {
+        PythonVariant::Default => &FLAVORS,
+        PythonVariant::Debug => &DEBUG_FLAVORS,
+        PythonVariant::Freethreaded => &FREETHREADED_FLAVORS,
+        PythonVariant::FreethreadedDebug => &FREETHREADED_DEBUG_FLAVORS,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ReleaseAsset {
+    pub version: String,
+    pub triple: String,
+    pub flavor: String,
+}
+
+fn map_triple(triple: &str) -> Option<&str> {
+    let triple = match triple {
+        "x86_64-pc-windows-msvc-shared" => "x86_64-pc-windows-msvc",
+        "i686-pc-windows-msvc-shared" => "i686-pc-windows-msvc",
+        "ppc64le-unknown-linux-gnu" => "powerpc64le-unknown-linux-gnu",
+        "riscv64-unknown-linux-gnu" => "riscv64gc-unknown-linux-gnu",
+        // Static builds and micro-architecture levels (x86_64_v3)
+        // are not wanted, so filter them out
+        other if other.ends_with("-static") || other.starts_with("x86_64_v") => return None,
+        other => other,
+    };
+
+    Some(triple)
+}
+
+// Newer releases are in the format of:
+//   cpython-3.12.1+20240107-x86_64-unknown-linux-gnu-install_only.tar.gz
+//   cpython-3.10.2+20220227-aarch64-apple-darwin-pgo+lto-full.tar.zst
+//   cpython-3.13.1+20250115-x86_64-unknown-linux-gnu-freethreaded+pgo+lto-full.tar.zst
+// Older releases are not supported and are loaded from the fallback index.
+pub fn parse_asset_name(
+    name: &str,
+    tag: &str,
+    variant: PythonVariant,
+    regex: &Regex,
+) -> Option<ReleaseAsset> {
+    let (version, rest) = name.strip_prefix("cpython-")?.split_once('+')?;
+    let rest = rest.strip_prefix(tag)?.strip_prefix('-')?;
+
+    // Flavors of other variants will not match, as the triple
+    // must be separated from the flavor with a dash
+    let (triple, flavor) = get_flavors(variant).iter().find_map(|(flavor, _)| {
+        rest.strip_suffix(flavor)
+            .and_then(|triple| triple.strip_suffix('-'))
+            .map(|triple| (triple, *flavor))
+    })?;
+
+    Some(ReleaseAsset {
+        version: from_python_version(version.to_owned(), regex)?,
+        triple: map_triple(triple)?.to_owned(),
+        flavor: flavor.to_owned(),
+    })
+}
+
+// Newer releases publish a single checksums file for all assets,
+// in the format of "<checksum>  <file>", which doubles as an asset list.
+pub fn parse_checksums_file(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .map(|name| name.to_owned())
+        .collect()
+}
+
+// Sortable key for versions returned by `from_python_version`: 3.14.0-a.4
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+struct VersionKey {
+    parts: Vec<u32>,
+    // Releases are sorted after their pre-releases
+    release: bool,
+    pre: (String, u32),
+}
+
+impl VersionKey {
+    fn parse(version: &str) -> Self {
+        let (version, pre) = version.split_once('-').unwrap_or((version, ""));
+        let (pre_id, pre_num) = pre.split_once('.').unwrap_or((pre, "0"));
+
+        Self {
+            parts: version
+                .split('.')
+                .map_while(|part| part.parse().ok())
+                .collect(),
+            release: pre.is_empty(),
+            pre: (pre_id.to_owned(), pre_num.parse().unwrap_or_default()),
+        }
+    }
+
+    // The major and minor parts: 3.12
+    fn line(&self) -> &[u32] {
+        &self.parts[..self.parts.len().min(2)]
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ReleaseLookup<'a> {
+    /// The asset name of the most wanted flavor, and its archive prefix.
+    Found(&'a str, &'static str),
+    /// The version was published in a newer release.
+    Newer,
+    /// The version was published in an older release.
+    Older,
+    /// The version was never published for the triple.
+    Missing,
+}
+
+// Each release only contains the latest patch of every supported minor
+// version at the time, so compare against them to know which direction
+// to search in, instead of loading every release.
+pub fn lookup_release<'a>(
+    names: &'a [String],
+    tag: &str,
+    version: &str,
+    triple: &str,
+    variant: PythonVariant,
+    regex: &Regex,
+) -> ReleaseLookup<'a> {
+    let assets = names
+        .iter()
+        .filter_map(|name| {
+            parse_asset_name(name, tag, variant, regex).map(|info| (name.as_str(), info))
+        })
+        .collect::<Vec<_>>();
+
+    // Select the most wanted flavor that is available
+    if let Some(found) = get_flavors(variant).iter().find_map(|(flavor, prefix)| {
+        assets
+            .iter()
+            .find(|(_, info)| {
+                info.version == version && info.triple == triple && info.flavor == *flavor
+            })
+            .map(|(name, _)| ReleaseLookup::Found(name, prefix))
+    }) {
+        return found;
+    }
+
+    // Releases using the old naming scheme (or without variants) can't be parsed
+    if assets.is_empty() {
+        return ReleaseLookup::Newer;
+    }
+
+    let wanted = VersionKey::parse(version);
+    let versions = assets
+        .iter()
+        .map(|(_, info)| VersionKey::parse(&info.version))
+        .collect::<Vec<_>>();
+
+    if let Some(latest) = versions
+        .iter()
+        .filter(|other| other.line() == wanted.line())
+        .max()
+    {
+        return match latest.cmp(&wanted) {
+            Ordering::Greater => ReleaseLookup::Older,
+            Ordering::Less => ReleaseLookup::Newer,
+            Ordering::Equal => ReleaseLookup::Missing,
+        };
+    }
+
+    // Minor versions are dropped once they reach end-of-life
+    if versions.iter().all(|other| other.line() > wanted.line()) {
+        ReleaseLookup::Older
+    } else if versions.iter().all(|other| other.line() < wanted.line()) {
+        ReleaseLookup::Newer
+    } else {
+        ReleaseLookup::Missing
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::version::create_version_regex;
+
+    #[test]
+    fn parses_install_only() {
+        assert_eq!(
+            parse_asset_name(
+                "cpython-3.12.1+20240107-x86_64-unknown-linux-gnu-install_only.tar.gz",
+                "20240107",
+                PythonVariant::Default,
+                &create_version_regex()
+            ),
+            Some(ReleaseAsset {
+                version: "3.12.1".into(),
+                triple: "x86_64-unknown-linux-gnu".into(),
+                flavor: "install_only.tar.gz".into(),
+            })
+        );
+        assert_eq!(
+            parse_asset_name(
+                "cpython-3.13.1+20250115-aarch64-apple-darwin-install_only_stripped.tar.gz",
+                "20250115",
+                PythonVariant::Default,
+                &create_version_regex()
+            ),
+            Some(ReleaseAsset {
+                version: "3.13.1".into(),
+                triple: "aarch64-apple-darwin".into(),
+                flavor: "install_only_stripped.tar.gz".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_full() {
+        assert_eq!(
+            parse_asset_name(
+                "cpython-3.10.2+20220227-aarch64-apple-darwin-pgo+lto-full.tar.zst",
+                "20220227",
+                PythonVariant::Default,
+                &create_version_regex()
+            ),
+            Some(ReleaseAsset {
+                version: "3.10.2".into(),
+                triple: "aarch64-apple-darwin".into(),
+                flavor: "pgo+lto-full.tar.zst".into(),
+            })
+        );
+        assert_eq!(
+            parse_asset_name(
+                "cpython-3.14.0a4+20250115-x86_64-pc-windows-msvc-shared-pgo-full.tar.zst",
+                "20250115",
+                PythonVariant::Default,
+                &create_version_regex()
+            ),
+            Some(ReleaseAsset {
+                version: "3.14.0-a.4".into(),
+                triple: "x86_64-pc-windows-msvc".into(),
+                flavor: "pgo-full.tar.zst".into(),
+            })
+        );
+    }
+
+    #[test]
+    fn maps_triples() {
+        assert_eq!(
+            parse_asset_name(
+                "cpython-3.12.1+20240107-ppc64le-unknown-linux-gnu-install_only.tar.gz",
+                "20240107",
+                PythonVariant::Default,
+                &create_version_regex()
+            )
+            .unwrap()
+            .triple,
+            "powerpc64le-unknown-linux-gnu"
+        );
+    }
+
+    #[test]
+    fn ignores_unwanted_assets() {
+        let regex = create_version_regex();
+
+        for name in [
+            "SHA256SUMS",
+            "cpython-3.12.1+20240107-x86_64-unknown-linux-gnu-install_only.tar.gz.sha256",
+            "cpython-3.12.1+20240107-x86_64-unknown-linux-gnu-debug-full.tar.zst",
+            "cpython-3.13.1+20250115-x86_64-unknown-linux-gnu-freethreaded+pgo+lto-full.tar.zst",
+            "cpython-3.12.1+20240107-x86_64_v3-unknown-linux-gnu-install_only.tar.gz",
+            "cpython-3.12.1+20240107-x86_64-pc-windows-msvc-static-noopt-full.tar.zst",
+            "cpython-3.7.3-linux64-20190427T2308.tar.zst",
+        ] {
+            assert_eq!(
+                parse_asset_name(name, "20240107", PythonVariant::Default, &regex),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn parses_variants() {
+        let regex = create_version_regex();
+        let debug = "cpython-3.13.1+20250115-x86_64-unknown-linux-gnu-debug-full.tar.zst";
+        let freethreaded =
+            "cpython-3.13.1+20250115-x86_64-unknown-linux-gnu-freethreaded+pgo+lto-full.tar.zst";
+        let freethreaded_debug =
+            "cpython-3.13.1+20250115-x86_64-unknown-linux-gnu-freethreaded+debug-full.tar.zst";
+
+        assert_eq!(
+            parse_asset_name(debug, "20250115", PythonVariant::Debug, &regex)
+                .unwrap()
+                .flavor,
+            "debug-full.tar.zst"
+        );
+        assert_eq!(
+            parse_asset_name(
+                freethreaded,
+                "20250115",
+                PythonVariant::Freethreaded,
+                &regex
+            )
+            .unwrap()
+            .flavor,
+            "freethreaded+pgo+lto-full.tar.zst"
+        );
+        assert_eq!(
+            parse_asset_name(
+                freethreaded_debug,
+                "20250115",
+                PythonVariant::FreethreadedDebug,
+                &regex
+            )
+            .unwrap()
+            .triple,
+            "x86_64-unknown-linux-gnu"
+        );
+
+        // Variants must not cross-match
+        assert_eq!(
+            parse_asset_name(freethreaded_debug, "20250115", PythonVariant::Debug, &regex),
+            None
+        );
+        assert_eq!(
+            parse_asset_name(debug, "20250115", PythonVariant::Freethreaded, &regex),
+            None
+        );
+    }
+
+    fn create_names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_checksums_file() {
+        assert_eq!(
+            parse_checksums_file(
+                "abc123  cpython-3.13.1+20250115-aarch64-apple-darwin-install_only.tar.gz\n\ndef456  cpython-3.12.8+20250115-aarch64-apple-darwin-install_only.tar.gz\n"
+            ),
+            vec![
+                "cpython-3.13.1+20250115-aarch64-apple-darwin-install_only.tar.gz",
+                "cpython-3.12.8+20250115-aarch64-apple-darwin-install_only.tar.gz"
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_most_wanted_flavor() {
+        let names = create_names(&[
+            "cpython-3.12.8+20250115-x86_64-unknown-linux-gnu-pgo+lto-full.tar.zst",
+            "cpython-3.12.8+20250115-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            "cpython-3.12.8+20250115-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz",
+            "cpython-3.12.8+20250115-aarch64-apple-darwin-install_only.tar.gz",
+        ]);
+
+        assert_eq!(
+            lookup_release(
+                &names,
+                "20250115",
+                "3.12.8",
+                "x86_64-unknown-linux-gnu",
+                PythonVariant::Default,
+                &create_version_regex()
+            ),
+            ReleaseLookup::Found(
+                "cpython-3.12.8+20250115-x86_64-unknown-linux-gnu-install_only_stripped.tar.gz",
+                "python"
+            )
+        );
+        assert_eq!(
+            lookup_release(
+                &names,
+                "20250115",
+                "3.12.8",
+                "aarch64-apple-darwin",
+                PythonVariant::Default,
+                &create_version_regex()
+            ),
+            ReleaseLookup::Found(
+                "cpython-3.12.8+20250115-aarch64-apple-darwin-install_only.tar.gz",
+                "python"
+            )
+        );
+    }
+
+    #[test]
+    fn searches_by_patch_version() {
+        let regex = create_version_regex();
+        let names = create_names(&[
+            "cpython-3.12.8+20250115-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            "cpython-3.13.1+20250115-x86_64-unknown-linux-gnu-install_only.tar.gz",
+        ]);
+        let lookup = |version| {
+            lookup_release(
+                &names,
+                "20250115",
+                version,
+                "x86_64-unknown-linux-gnu",
+                PythonVariant::Default,
+                &regex,
+            )
+        };
+
+        assert_eq!(lookup("3.12.1"), ReleaseLookup::Older);
+        assert_eq!(lookup("3.13.2"), ReleaseLookup::Newer);
+        assert_eq!(lookup("3.13.0-rc.1"), ReleaseLookup::Older);
+    }
+
+    #[test]
+    fn searches_by_minor_version() {
+        let regex = create_version_regex();
+        let names = create_names(&[
+            "cpython-3.10.16+20250115-x86_64-unknown-linux-gnu-install_only.tar.gz",
+            "cpython-3.12.8+20250115-x86_64-unknown-linux-gnu-install_only.tar.gz",
+        ]);
+        let lookup = |version| {
+            lookup_release(
+                &names,
+                "20250115",
+                version,
+                "x86_64-unknown-linux-gnu",
+                PythonVariant::Default,
+                &regex,
+            )
+        };
+
+        assert_eq!(lookup("3.8.20"), ReleaseLookup::Older);
+        assert_eq!(lookup("3.14.0-a.4"), ReleaseLookup::Newer);
+        assert_eq!(lookup("3.11.0"), ReleaseLookup::Missing);
+    }
+
+    #[test]
+    fn reports_missing_triples() {
+        let names =
+            create_names(&["cpython-3.12.8+20250115-x86_64-unknown-linux-gnu-install_only.tar.gz"]);
+
+        assert_eq!(
+            lookup_release(
+                &names,
+                "20250115",
+                "3.12.8",
+                "riscv64gc-unknown-linux-gnu",
+                PythonVariant::Default,
+                &create_version_regex()
+            ),
+            ReleaseLookup::Missing
+        );
+    }
+
+    #[test]
+    fn searches_newer_for_unparseable_releases() {
+        let names = create_names(&["cpython-3.7.3-linux64-20190427T2308.tar.zst"]);
+
+        assert_eq!(
+            lookup_release(
+                &names,
+                "20190427",
+                "3.7.3",
+                "x86_64-unknown-linux-gnu",
+                PythonVariant::Default,
+                &create_version_regex()
+            ),
+            ReleaseLookup::Newer
+        );
+    }
+}
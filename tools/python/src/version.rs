@@ -2,6 +2,13 @@
 
 use regex::Regex;
 
+pub fn create_version_regex() -> Regex {
+    Regex::new(
+        r"v?(?<major>[0-9]+)\.(?<minor>[0-9]+)(?:\.(?<patch>[0-9]+))?(?:(?<pre>a|b|c|rc)(?<preid>[0-9]+))?",
+    )
+    .unwrap()
+}
+
 pub fn from_python_version(version: String, regex: &Regex) -> Option<String> {
     let caps = regex.captures(&version)?;
 
@@ -4,4 +4,119 @@ mod python_tool {
     use super::*;
 
     generate_download_install_tests!("python-test", "3.10.0");
+
+    mod graalpy {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn supports_linux_x64() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("python-test", |config| {
+                    config.host(HostOS::Linux, HostArch::X64);
+                })
+                .await;
+
+            assert_eq!(
+                plugin
+                    .download_prebuilt(DownloadPrebuiltInput {
+                        context: ToolContext {
+                            version: VersionSpec::parse("graalpy-24.1.1").unwrap(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .await,
+                DownloadPrebuiltOutput {
+                    archive_prefix: Some("graalpy-24.1.1-linux-amd64".into()),
+                    checksum_name: Some("graalpy-24.1.1-linux-amd64.tar.gz.sha256".into()),
+                    checksum_public_key: None,
+                    checksum_url: Some("https://github.com/oracle/graalpython/releases/download/graal-24.1.1/graalpy-24.1.1-linux-amd64.tar.gz.sha256".into()),
+                    download_name: Some("graalpy-24.1.1-linux-amd64.tar.gz".into()),
+                    download_url: "https://github.com/oracle/graalpython/releases/download/graal-24.1.1/graalpy-24.1.1-linux-amd64.tar.gz".into()
+                }
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn supports_macos_arm64() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("python-test", |config| {
+                    config.host(HostOS::MacOS, HostArch::Arm64);
+                })
+                .await;
+
+            assert_eq!(
+                plugin
+                    .download_prebuilt(DownloadPrebuiltInput {
+                        context: ToolContext {
+                            version: VersionSpec::parse("graalpy-24.1.1").unwrap(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .await,
+                DownloadPrebuiltOutput {
+                    archive_prefix: Some("graalpy-24.1.1-macos-aarch64".into()),
+                    checksum_name: Some("graalpy-24.1.1-macos-aarch64.tar.gz.sha256".into()),
+                    checksum_public_key: None,
+                    checksum_url: Some("https://github.com/oracle/graalpython/releases/download/graal-24.1.1/graalpy-24.1.1-macos-aarch64.tar.gz.sha256".into()),
+                    download_name: Some("graalpy-24.1.1-macos-aarch64.tar.gz".into()),
+                    download_url: "https://github.com/oracle/graalpython/releases/download/graal-24.1.1/graalpy-24.1.1-macos-aarch64.tar.gz".into()
+                }
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn supports_windows_x64() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("python-test", |config| {
+                    config.host(HostOS::Windows, HostArch::X64);
+                })
+                .await;
+
+            assert_eq!(
+                plugin
+                    .download_prebuilt(DownloadPrebuiltInput {
+                        context: ToolContext {
+                            version: VersionSpec::parse("graalpy-24.1.1").unwrap(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    })
+                    .await,
+                DownloadPrebuiltOutput {
+                    archive_prefix: Some("graalpy-24.1.1-windows-amd64".into()),
+                    checksum_name: Some("graalpy-24.1.1-windows-amd64.zip.sha256".into()),
+                    checksum_public_key: None,
+                    checksum_url: Some("https://github.com/oracle/graalpython/releases/download/graal-24.1.1/graalpy-24.1.1-windows-amd64.zip.sha256".into()),
+                    download_name: Some("graalpy-24.1.1-windows-amd64.zip".into()),
+                    download_url: "https://github.com/oracle/graalpython/releases/download/graal-24.1.1/graalpy-24.1.1-windows-amd64.zip".into()
+                }
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        #[should_panic(expected = "unsupported architecture x86 for windows.")]
+        async fn doesnt_support_windows_x86() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("python-test", |config| {
+                    config.host(HostOS::Windows, HostArch::X86);
+                })
+                .await;
+
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("graalpy-24.1.1").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await;
+        }
+    }
 }
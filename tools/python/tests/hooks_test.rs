@@ -0,0 +1,132 @@
+use proto_pdk_test_utils::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PythonPluginConfig {
+    pub per_version_user_base: bool,
+    pub venv_activate: bool,
+}
+
+mod python_tool {
+    use super::*;
+
+    mod pre_run {
+        use super::*;
+
+        fn create_context() -> ToolContext {
+            ToolContext {
+                tool_dir: VirtualPath::WithReal {
+                    path: PathBuf::from("/proto/tools/python/3.12.0"),
+                    virtual_prefix: PathBuf::from("/proto"),
+                    real_prefix: PathBuf::from("/.proto"),
+                },
+                ..ToolContext::default()
+            }
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn does_nothing_if_not_configured() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox.create_plugin("python-test").await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(result.args, None);
+            assert_eq!(result.env, None);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn sets_user_base_per_version() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("python-test", |config| {
+                    config.tool_config(PythonPluginConfig {
+                        per_version_user_base: true,
+                        ..Default::default()
+                    });
+                })
+                .await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(
+                result.env,
+                Some(HashMap::from_iter([(
+                    "PYTHONUSERBASE".into(),
+                    PathBuf::from("/.proto/tools/python/3.12.0/user")
+                        .to_string_lossy()
+                        .to_string()
+                )]))
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn does_nothing_if_no_venv() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("python-test", |config| {
+                    config.tool_config(PythonPluginConfig {
+                        venv_activate: true,
+                        ..Default::default()
+                    });
+                })
+                .await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(result.env, None);
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn activates_venv() {
+            let sandbox = create_empty_proto_sandbox();
+            sandbox.create_file(".venv/pyvenv.cfg", "version = 3.12.0");
+
+            let plugin = sandbox
+                .create_plugin_with_config("python-test", |config| {
+                    config.tool_config(PythonPluginConfig {
+                        venv_activate: true,
+                        ..Default::default()
+                    });
+                })
+                .await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+            let env = result.env.unwrap();
+            let venv_dir = sandbox.path().join(".venv");
+            let bin_dir = venv_dir.join(if cfg!(windows) { "Scripts" } else { "bin" });
+
+            assert_eq!(
+                env.get("VIRTUAL_ENV").unwrap(),
+                &venv_dir.to_string_lossy().to_string()
+            );
+            assert!(env.get("PATH").unwrap().starts_with(&format!(
+                "{}{}",
+                bin_dir.to_string_lossy(),
+                if cfg!(windows) { ";" } else { ":" }
+            )));
+        }
+    }
+}
@@ -1,5 +1,11 @@
 use proto_pdk_test_utils::*;
 
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PythonPluginConfig {
+    pub variant: Option<String>,
+}
+
 mod python_tool {
     use super::*;
 
@@ -15,5 +21,22 @@ mod python_tool {
             metadata.plugin_version.unwrap().to_string(),
             env!("CARGO_PKG_VERSION")
         );
+        assert_eq!(metadata.inventory.version_suffix, None);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn registers_variant_suffix() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("python-test", |config| {
+                config.tool_config(PythonPluginConfig {
+                    variant: Some("freethreaded".into()),
+                });
+            })
+            .await;
+
+        let metadata = plugin.register_tool(ToolMetadataInput::default()).await;
+
+        assert_eq!(metadata.inventory.version_suffix, Some("t".into()));
     }
 }
@@ -1,5 +1,11 @@
 use proto_pdk_test_utils::*;
 
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct PythonPluginConfig {
+    pub variant: Option<String>,
+}
+
 mod python_tool {
     use super::*;
 
@@ -31,4 +37,220 @@ mod python_tool {
         assert!(output.aliases.contains_key("latest"));
         assert_eq!(output.aliases.get("latest"), output.latest.as_ref());
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_python_version_with_multiple_versions() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "# comment\nminiconda3-latest\n3.12.1\n3.11".into(),
+                    file: ".python-version".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.12.1").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_python_version_with_variant() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("python-test", |config| {
+                config.tool_config(PythonPluginConfig {
+                    variant: Some("freethreaded".into()),
+                });
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "3.13t".into(),
+                    file: ".python-version".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.13").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "setting to be")]
+    async fn errors_for_python_version_with_mismatched_variant() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        plugin
+            .parse_version_file(ParseVersionFileInput {
+                content: "3.13t".into(),
+                file: ".python-version".into(),
+                ..Default::default()
+            })
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_python_version_with_implementation() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "pypy3.10-7.3.15\n3.12.1".into(),
+                    file: ".python-version".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::Alias("pypy3.10-7.3.15".into())),
+            }
+        );
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "graalpy-24.1.1".into(),
+                    file: ".python-version".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::Alias("graalpy-24.1.1".into())),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_runtime_txt() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "python-3.11.4\n".into(),
+                    file: "runtime.txt".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.11.4").unwrap()),
+            }
+        );
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "nodejs 20.0.0\npython system 3.10.4".into(),
+                    file: ".tool-versions".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.10.4").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_pipfile() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content:
+                        "[packages]\nrequests = \"*\"\n\n[requires]\npython_version = \"3.11\""
+                            .into(),
+                    file: "Pipfile".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.11").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_pyproject_requires_python() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "[project]\nname = \"example\"\nrequires-python = \">=3.10,<3.13\""
+                        .into(),
+                    file: "pyproject.toml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse(">=3.10, <3.13").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_pyproject_poetry() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "[tool.poetry.dependencies]\npython = \"~=3.11\"\nrequests = { version = \"^2.0\" }".into(),
+                    file: "pyproject.toml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("^3.11").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ignores_pyproject_without_python() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "[project]\nname = \"example\"".into(),
+                    file: "pyproject.toml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput { version: None }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn ignores_pyproject_with_unsupported_specifier() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("python-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "[project]\nname = \"example\"\nrequires-python = \">=3.9.0b1\""
+                        .into(),
+                    file: "pyproject.toml".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput { version: None }
+        );
+    }
 }
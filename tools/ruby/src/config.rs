@@ -0,0 +1,19 @@
+#[derive(Debug, schematic::Schematic, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RubyPluginConfig {
+    pub bundler_from_lockfile: bool,
+    pub dist_url: String,
+    pub per_version_gem_home: bool,
+}
+
+impl Default for RubyPluginConfig {
+    fn default() -> Self {
+        Self {
+            bundler_from_lockfile: false,
+            dist_url:
+                "https://github.com/Homebrew/homebrew-portable-ruby/releases/download/{version}/{file}"
+                    .into(),
+            per_version_gem_home: false,
+        }
+    }
+}
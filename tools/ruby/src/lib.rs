@@ -1,5 +1,8 @@
+mod config;
 #[cfg(feature = "wasm")]
 mod proto;
+mod release;
+mod version;
 
 #[cfg(feature = "wasm")]
 pub use proto::*;
@@ -1,18 +1,48 @@
+use crate::config::RubyPluginConfig;
+use crate::release::{find_asset_sha256, get_release_api_url, has_asset};
+use crate::version::{parse_bundled_with, parse_gemfile, parse_gemfile_lock, strip_engine};
 use extism_pdk::*;
 use proto_pdk::*;
+use schematic::SchemaBuilder;
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use tool_common::parse_tool_versions;
 
 #[host_fn]
 extern "ExtismHost" {
     fn exec_command(input: Json<ExecCommandInput>) -> Json<ExecCommandOutput>;
+    fn from_virtual_path(input: String) -> String;
+    fn get_env_var(name: String) -> String;
+    fn send_request(input: Json<SendRequestInput>) -> Json<SendRequestOutput>;
+    fn to_virtual_path(input: String) -> String;
+}
+
+static NAME: &str = "Ruby";
+
+// Portable pre-builts are only available for glibc-based Linux,
+// so other platforms must build from source
+fn is_prebuilt_supported(env: &HostEnvironment) -> bool {
+    env.os.is_linux()
+        && matches!(env.arch, HostArch::X64 | HostArch::Arm64)
+        && env.libc != HostLibc::Musl
 }
 
 #[plugin_fn]
 pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMetadataOutput>> {
+    let env = get_host_environment()?;
+
     Ok(Json(ToolMetadataOutput {
-        name: "Ruby".into(),
+        name: NAME.into(),
         type_of: PluginType::Language,
-        default_install_strategy: InstallStrategy::BuildFromSource,
+        config_schema: Some(SchemaBuilder::build_root::<RubyPluginConfig>()),
+        default_install_strategy: if is_prebuilt_supported(&env) {
+            InstallStrategy::DownloadPrebuilt
+        } else {
+            InstallStrategy::BuildFromSource
+        },
         minimum_proto_version: Some(Version::new(0, 42, 0)),
         plugin_version: Version::parse(env!("CARGO_PKG_VERSION")).ok(),
         unstable: Switch::Message("Windows is currently not supported.".into()),
@@ -23,11 +53,65 @@ pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMeta
 #[plugin_fn]
 pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
     Ok(Json(DetectVersionOutput {
-        files: vec![".ruby-version".into()],
+        files: vec![
+            ".ruby-version".into(),
+            "Gemfile".into(),
+            "Gemfile.lock".into(),
+            ".tool-versions".into(),
+        ],
         ignore: vec!["vendor".into()],
     }))
 }
 
+fn unsupported_engine(engine: String) -> Error {
+    anyhow!(
+        "Ruby engine <id>{engine}</id> is not supported, only the default engine (CRuby) can be installed."
+    )
+}
+
+#[plugin_fn]
+pub fn parse_version_file(
+    Json(input): Json<ParseVersionFileInput>,
+) -> FnResult<Json<ParseVersionFileOutput>> {
+    let mut version = None;
+
+    if input.file == "Gemfile" {
+        if let Some(constraint) = parse_gemfile(&input.content).map_err(unsupported_engine)? {
+            version = Some(UnresolvedVersionSpec::parse(constraint)?);
+        }
+    } else if input.file == "Gemfile.lock" {
+        if let Some(value) = parse_gemfile_lock(&input.content).map_err(unsupported_engine)? {
+            version = Some(UnresolvedVersionSpec::parse(value)?);
+        }
+    } else if input.file == ".tool-versions" {
+        let values = parse_tool_versions(&input.content, "ruby");
+
+        // Multiple versions may be listed, so fall through to the next
+        // entry when one is another engine or not a valid version
+        version = values.iter().find_map(|value| {
+            strip_engine(value)
+                .ok()
+                .and_then(|value| UnresolvedVersionSpec::parse(value).ok())
+        });
+
+        if version.is_none() {
+            if let Some(value) = values.first() {
+                strip_engine(value).map_err(unsupported_engine)?;
+            }
+        }
+    } else {
+        let value = input.content.trim();
+
+        if !value.is_empty() {
+            version = Some(UnresolvedVersionSpec::parse(
+                strip_engine(value).map_err(unsupported_engine)?,
+            )?);
+        }
+    }
+
+    Ok(Json(ParseVersionFileOutput { version }))
+}
+
 #[plugin_fn]
 pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVersionsOutput>> {
     let tags = load_git_tags("https://github.com/ruby/ruby")?
@@ -54,6 +138,193 @@ pub fn load_versions(Json(_): Json<LoadVersionsInput>) -> FnResult<Json<LoadVers
     Ok(Json(LoadVersionsOutput::from(tags)?))
 }
 
+// https://github.com/Homebrew/homebrew-portable-ruby/releases
+fn get_bottle_name(env: &HostEnvironment, version: &VersionSpec) -> String {
+    let arch = match env.arch {
+        HostArch::Arm64 => "arm64",
+        _ => "x86_64",
+    };
+
+    format!("portable-ruby-{version}.{arch}_linux.bottle.tar.gz")
+}
+
+// Homebrew only publishes a subset of Ruby versions, so check the release
+// for a matching bottle. Mirrors can't be checked, so assume it exists.
+fn has_prebuilt(env: &HostEnvironment, version: &VersionSpec) -> AnyResult<bool> {
+    let config = get_tool_config::<RubyPluginConfig>()?;
+
+    let Some(release_url) = get_release_api_url(&config.dist_url, &version.to_string()) else {
+        return Ok(true);
+    };
+
+    let response = send_request!(release_url.as_str());
+
+    Ok(match response.status {
+        200 => has_asset(&response.text()?, &get_bottle_name(env, version))?,
+        404 => false,
+        // Rate limited, etc, so let the download report the failure
+        _ => true,
+    })
+}
+
+#[plugin_fn]
+pub fn native_install(
+    Json(input): Json<NativeInstallInput>,
+) -> FnResult<Json<NativeInstallOutput>> {
+    let env = get_host_environment()?;
+    let version = &input.context.version;
+
+    // Continue with the default install strategy
+    if !is_prebuilt_supported(&env) || version.is_canary() || has_prebuilt(&env, version)? {
+        return Ok(Json(NativeInstallOutput {
+            skip_install: true,
+            ..NativeInstallOutput::default()
+        }));
+    }
+
+    debug!("No pre-built available for Ruby <hash>{version}</hash>, building from source with <shell>ruby-build</shell>");
+
+    // Share the checkout with the builder used by `build_instructions`
+    let builder_dir = PathBuf::from("/proto/builders/ruby-build");
+    let builder_dir_arg = real_path!(buf, builder_dir).to_string_lossy().to_string();
+
+    let checkout = if builder_dir.join(".git").exists() {
+        exec_command!(
+            inherit,
+            "git",
+            ["-C", builder_dir_arg.as_str(), "pull", "--ff-only"]
+        )
+    } else {
+        exec_command!(
+            inherit,
+            "git",
+            [
+                "clone",
+                "--depth",
+                "1",
+                "https://github.com/rbenv/ruby-build.git",
+                builder_dir_arg.as_str(),
+            ]
+        )
+    };
+
+    if checkout.exit_code != 0 {
+        return Ok(Json(NativeInstallOutput {
+            error: Some(format!(
+                "Failed to checkout ruby-build to {builder_dir_arg}."
+            )),
+            ..NativeInstallOutput::default()
+        }));
+    }
+
+    let Some(install_dir) = input
+        .install_dir
+        .real_path()
+        .map(|dir| dir.to_string_lossy().to_string())
+    else {
+        return Ok(Json(NativeInstallOutput {
+            error: Some("Unable to determine the Ruby install directory.".into()),
+            ..NativeInstallOutput::default()
+        }));
+    };
+
+    // Commands are converted from virtual paths on the host
+    let build = exec_command!(
+        inherit,
+        builder_dir.join("bin/ruby-build").to_string_lossy(),
+        [
+            "--verbose",
+            version.to_string().as_str(),
+            install_dir.as_str(),
+        ]
+    );
+
+    Ok(Json(NativeInstallOutput {
+        error: if build.exit_code == 0 {
+            None
+        } else {
+            Some(format!(
+                "Failed to build Ruby {version} with ruby-build. See https://github.com/rbenv/ruby-build/wiki for the required system dependencies."
+            ))
+        },
+        installed: build.exit_code == 0,
+        ..NativeInstallOutput::default()
+    }))
+}
+
+#[plugin_fn]
+pub fn download_prebuilt(
+    Json(input): Json<DownloadPrebuiltInput>,
+) -> FnResult<Json<DownloadPrebuiltOutput>> {
+    let env = get_host_environment()?;
+
+    check_supported_os_and_arch(
+        NAME,
+        &env,
+        permutations! [
+            HostOS::Linux => [HostArch::X64, HostArch::Arm64],
+        ],
+    )?;
+
+    if !is_prebuilt_supported(&env) {
+        return Err(plugin_err!(
+            "Ruby pre-builts are only available for glibc, not musl. Try building from source with <shell>--build</shell>."
+        ));
+    }
+
+    let version = input.context.version;
+
+    if version.is_canary() {
+        return Err(plugin_err!(PluginError::UnsupportedCanary {
+            tool: NAME.into()
+        }));
+    }
+
+    let filename = get_bottle_name(&env, &version);
+    let config = get_tool_config::<RubyPluginConfig>()?;
+
+    Ok(Json(DownloadPrebuiltOutput {
+        archive_prefix: Some(format!("portable-ruby/{version}")),
+        // GitHub releases contain the published SHA-256 of each bottle,
+        // which is handled by the `verify_checksum` function
+        checksum_url: get_release_api_url(&config.dist_url, &version.to_string()),
+        download_url: config
+            .dist_url
+            .replace("{version}", &version.to_string())
+            .replace("{file}", &filename),
+        download_name: Some(filename),
+        ..DownloadPrebuiltOutput::default()
+    }))
+}
+
+#[plugin_fn]
+pub fn verify_checksum(
+    Json(input): Json<VerifyChecksumInput>,
+) -> FnResult<Json<VerifyChecksumOutput>> {
+    let download_name = input
+        .download_file
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    let Some(expected) =
+        find_asset_sha256(&fs::read_to_string(&input.checksum_file)?, &download_name)?
+    else {
+        debug!("No published checksum for <file>{download_name}</file>, skipping verification");
+
+        return Ok(Json(VerifyChecksumOutput { verified: true }));
+    };
+
+    let mut file = fs::File::open(&input.download_file)?;
+    let mut sha = Sha256::new();
+
+    io::copy(&mut file, &mut sha)?;
+
+    Ok(Json(VerifyChecksumOutput {
+        verified: format!("{:x}", sha.finalize()) == expected,
+    }))
+}
+
 #[plugin_fn]
 pub fn build_instructions(
     Json(input): Json<BuildInstructionsInput>,
@@ -121,6 +392,16 @@ pub fn locate_executables(
     Json(_): Json<LocateExecutablesInput>,
 ) -> FnResult<Json<LocateExecutablesOutput>> {
     let env = get_host_environment()?;
+    let config = get_tool_config::<RubyPluginConfig>()?;
+
+    // Bins of the current gem home, then per-version and default gem homes
+    let mut globals_lookup_dirs = vec!["$GEM_HOME/bin".into()];
+
+    if config.per_version_gem_home {
+        globals_lookup_dirs.push("$TOOL_DIR/gems/bin".into());
+    }
+
+    globals_lookup_dirs.push("$TOOL_DIR/bin".into());
 
     Ok(Json(LocateExecutablesOutput {
         exes: HashMap::from_iter([
@@ -142,7 +423,112 @@ pub fn locate_executables(
             ),
         ]),
         exes_dir: Some("bin".into()),
-        globals_lookup_dirs: vec![],
+        globals_lookup_dirs,
         ..LocateExecutablesOutput::default()
     }))
 }
+
+// Gems compiled against one Ruby version are not compatible with
+// another, so keep them within the version's tool directory
+fn get_gem_home(context: &ToolContext) -> Option<String> {
+    context
+        .tool_dir
+        .join("gems")
+        .real_path()
+        .map(|dir| dir.to_string_lossy().to_string())
+}
+
+#[plugin_fn]
+pub fn pre_run(Json(input): Json<RunHook>) -> FnResult<Json<RunHookResult>> {
+    let mut result = RunHookResult::default();
+    let config = get_tool_config::<RubyPluginConfig>()?;
+
+    // Respect a gem home that has already been configured (rbenv, bundler, etc)
+    if !config.per_version_gem_home || host_env!("GEM_HOME").is_some_and(|value| !value.is_empty())
+    {
+        return Ok(Json(result));
+    }
+
+    let Some(gem_home) = get_gem_home(&input.context) else {
+        return Ok(Json(result));
+    };
+
+    let env = get_host_environment()?;
+    let mut gem_path = gem_home.clone();
+
+    // Setting GEM_PATH replaces the default path, so include the
+    // gems that are bundled with Ruby (rake, minitest, etc)
+    if let Some(version) = input.context.version.as_version() {
+        if let Some(dir) = input
+            .context
+            .tool_dir
+            .join(format!(
+                "lib/ruby/gems/{}.{}.0",
+                version.major, version.minor
+            ))
+            .real_path()
+        {
+            gem_path.push_str(env.os.for_native(":", ";"));
+            gem_path.push_str(&dir.to_string_lossy());
+        }
+    }
+
+    let vars = result.env.get_or_insert(HashMap::default());
+    vars.insert("GEM_HOME".into(), gem_home);
+    vars.insert("GEM_PATH".into(), gem_path);
+
+    Ok(Json(result))
+}
+
+#[plugin_fn]
+pub fn post_install(Json(input): Json<InstallHook>) -> FnResult<()> {
+    let config = get_tool_config::<RubyPluginConfig>()?;
+
+    if !config.bundler_from_lockfile {
+        return Ok(());
+    }
+
+    let lockfile = virtual_path!("/cwd").join("Gemfile.lock");
+
+    if !lockfile.exists() {
+        return Ok(());
+    }
+
+    let Some(bundler_version) = parse_bundled_with(&fs::read_to_string(&lockfile)?) else {
+        return Ok(());
+    };
+
+    let env = get_host_environment()?;
+
+    let Some(gem) = input
+        .context
+        .tool_dir
+        .join(env.os.get_exe_name("bin/gem"))
+        .real_path()
+    else {
+        return Ok(());
+    };
+
+    debug!("Installing bundler <hash>{bundler_version}</hash> from <path>Gemfile.lock</path>");
+
+    let mut command = ExecCommandInput::inherit(
+        gem.to_string_lossy(),
+        [
+            "install",
+            "bundler",
+            "--version",
+            bundler_version.as_str(),
+            "--no-document",
+        ],
+    );
+
+    if config.per_version_gem_home {
+        if let Some(gem_home) = get_gem_home(&input.context) {
+            command.env.insert("GEM_HOME".into(), gem_home);
+        }
+    }
+
+    exec_command!(input, command);
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct ReleaseAsset {
+    name: String,
+    digest: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Release {
+    assets: Vec<ReleaseAsset>,
+}
+
+/// Derive the GitHub API URL of a release from a GitHub release download URL:
+/// `https://github.com/{owner}/{repo}/releases/download/{version}/{file}`.
+/// Other hosts (mirrors) do not publish release metadata.
+pub fn get_release_api_url(dist_url: &str, version: &str) -> Option<String> {
+    let path = dist_url.strip_prefix("https://github.com/")?;
+    let (repo, rest) = path.split_once("/releases/download/")?;
+
+    if repo.split('/').count() != 2 || !rest.starts_with("{version}/") {
+        return None;
+    }
+
+    Some(format!(
+        "https://api.github.com/repos/{repo}/releases/tags/{version}"
+    ))
+}
+
+/// Check whether a GitHub release JSON payload contains an asset.
+pub fn has_asset(release: &str, name: &str) -> Result<bool, serde_json::Error> {
+    let release: Release = serde_json::from_str(release)?;
+
+    Ok(release.assets.iter().any(|asset| asset.name == name))
+}
+
+/// Extract the published SHA-256 of an asset from a GitHub release
+/// (`/repos/{owner}/{repo}/releases/tags/{tag}`) JSON payload.
+pub fn find_asset_sha256(release: &str, name: &str) -> Result<Option<String>, serde_json::Error> {
+    let release: Release = serde_json::from_str(release)?;
+
+    Ok(release
+        .assets
+        .into_iter()
+        .find(|asset| asset.name == name)
+        .and_then(|asset| asset.digest)
+        .and_then(|digest| {
+            digest
+                .strip_prefix("sha256:")
+                .map(|checksum| checksum.to_lowercase())
+        }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static RELEASE: &str = r#"{
+        "tag_name": "3.4.4",
+        "assets": [
+            {
+                "name": "portable-ruby-3.4.4.arm64_linux.bottle.tar.gz",
+                "digest": "sha256:3C3B1DFA6A1D2F5A4E0A0F36B4E0F9C6A76E7AB8D59A0C8F0E3C5B1AE2F4D6C8"
+            },
+            {
+                "name": "portable-ruby-3.4.4.x86_64_linux.bottle.tar.gz",
+                "digest": null
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn derives_api_url_from_github() {
+        assert_eq!(
+            get_release_api_url(
+                "https://github.com/Homebrew/homebrew-portable-ruby/releases/download/{version}/{file}",
+                "3.4.4"
+            ),
+            Some(
+                "https://api.github.com/repos/Homebrew/homebrew-portable-ruby/releases/tags/3.4.4"
+                    .into()
+            )
+        );
+        assert_eq!(
+            get_release_api_url(
+                "https://github.com/acme/ruby/releases/download/{version}/{file}",
+                "3.3.7"
+            ),
+            Some("https://api.github.com/repos/acme/ruby/releases/tags/3.3.7".into())
+        );
+    }
+
+    #[test]
+    fn doesnt_derive_api_url_for_mirrors() {
+        assert_eq!(
+            get_release_api_url(
+                "https://mirror.example.com/portable-ruby/{version}/{file}",
+                "3.4.4"
+            ),
+            None
+        );
+        assert_eq!(
+            get_release_api_url(
+                "https://github.com/acme/ruby/releases/download/latest/{file}",
+                "3.4.4"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn checks_for_assets() {
+        assert!(has_asset(RELEASE, "portable-ruby-3.4.4.arm64_linux.bottle.tar.gz").unwrap());
+        assert!(!has_asset(RELEASE, "portable-ruby-3.4.4.arm64_big_sur.bottle.tar.gz").unwrap());
+    }
+
+    #[test]
+    fn finds_asset_digest() {
+        assert_eq!(
+            find_asset_sha256(RELEASE, "portable-ruby-3.4.4.arm64_linux.bottle.tar.gz").unwrap(),
+            Some("3c3b1dfa6a1d2f5a4e0a0f36b4e0f9c6a76e7ab8d59a0c8f0e3c5b1ae2f4d6c8".into())
+        );
+    }
+
+    #[test]
+    fn returns_none_without_digest() {
+        assert_eq!(
+            find_asset_sha256(RELEASE, "portable-ruby-3.4.4.x86_64_linux.bottle.tar.gz").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn returns_none_for_unknown_asset() {
+        assert_eq!(
+            find_asset_sha256(RELEASE, "portable-ruby-3.4.4.arm64_big_sur.bottle.tar.gz").unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn errors_for_invalid_json() {
+        assert!(find_asset_sha256("Not Found", "portable-ruby.tar.gz").is_err());
+    }
+}
@@ -0,0 +1,274 @@
+#![allow(dead_code)]
+
+// Versions may be prefixed with the engine: ruby-3.2.2, jruby-9.4.0.0.
+// Only the default engine (CRuby/MRI) is supported, so the name of
+// any other engine is returned as an error.
+pub fn strip_engine(value: &str) -> Result<&str, String> {
+    let value = value.trim();
+
+    if value.starts_with(|c: char| c.is_ascii_digit()) {
+        return Ok(value);
+    }
+
+    match value.split_once('-') {
+        Some(("ruby", version)) => Ok(version),
+        Some((engine, _)) => Err(engine.to_owned()),
+        None => Err(value.to_owned()),
+    }
+}
+
+// Converts a RubyGems requirement into a semver compatible requirement.
+// https://guides.rubygems.org/patterns/#pessimistic-version-constraint
+fn from_gem_requirement(requirement: &str) -> Option<String> {
+    let requirement = requirement.trim();
+
+    if requirement.is_empty() {
+        return None;
+    }
+
+    for op in ["~>", ">=", "<=", "!=", "=", ">", "<"] {
+        let Some(version) = requirement.strip_prefix(op) else {
+            continue;
+        };
+
+        let version = version.trim();
+
+        return match op {
+            // Exclusions are not supported by semver requirements
+            "!=" => None,
+            // ~> 3.2 (>= 3.2, < 4.0), ~> 3.2.1 (>= 3.2.1, < 3.3)
+            "~>" => {
+                if version.matches('.').count() >= 2 {
+                    Some(format!("~{version}"))
+                } else {
+                    Some(format!("^{version}"))
+                }
+            }
+            _ => Some(format!("{op}{version}")),
+        };
+    }
+
+    Some(requirement.to_owned())
+}
+
+fn extract_quoted_strings(value: &str) -> Vec<&str> {
+    value
+        .split(['"', '\''])
+        .skip(1)
+        .step_by(2)
+        .collect::<Vec<_>>()
+}
+
+// ruby "~> 3.2"
+// ruby "3.2.2", engine: "jruby", engine_version: "9.4.0.0"
+// ruby ">= 3.0", "< 3.4"
+// https://bundler.io/guides/gemfile_ruby.html
+pub fn parse_gemfile(content: &str) -> Result<Option<String>, String> {
+    for line in content.lines() {
+        let line = line.trim();
+
+        let Some(args) = line
+            .strip_prefix("ruby ")
+            .or_else(|| line.strip_prefix("ruby("))
+        else {
+            continue;
+        };
+
+        // Strip trailing comments
+        let args = args.split_once('#').map(|(args, _)| args).unwrap_or(args);
+        let mut requirements = vec![];
+
+        for arg in args.split(',') {
+            let arg = arg.trim().trim_end_matches(')');
+
+            if let Some(option) = arg
+                .strip_prefix("engine:")
+                .or(arg.strip_prefix(":engine =>"))
+            {
+                let engine = extract_quoted_strings(option).join("");
+
+                if engine != "ruby" {
+                    return Err(engine);
+                }
+
+                continue;
+            }
+
+            // Other options (engine_version, patchlevel, file) are not requirements
+            if arg.contains(':') || arg.contains("=>") {
+                continue;
+            }
+
+            requirements.extend(
+                extract_quoted_strings(arg)
+                    .into_iter()
+                    .filter_map(from_gem_requirement),
+            );
+        }
+
+        return Ok(if requirements.is_empty() {
+            None
+        } else {
+            Some(requirements.join(", "))
+        });
+    }
+
+    Ok(None)
+}
+
+// RUBY VERSION
+//    ruby 3.2.2p53
+//    ruby 3.1.0p0 (jruby 9.4.0.0)
+pub fn parse_gemfile_lock(content: &str) -> Result<Option<String>, String> {
+    let mut in_section = false;
+
+    for line in content.lines() {
+        if line.trim() == "RUBY VERSION" {
+            in_section = true;
+            continue;
+        }
+
+        if !in_section {
+            continue;
+        }
+
+        let Some(value) = line.trim().strip_prefix("ruby ") else {
+            break;
+        };
+
+        if let Some((_, engine)) = value.split_once('(') {
+            let engine = engine.split_whitespace().next().unwrap_or_default();
+
+            if engine != "ruby" {
+                return Err(engine.to_owned());
+            }
+        }
+
+        let mut version = value.split_whitespace().next().unwrap_or_default();
+
+        // Remove the patch level: 3.2.2p53, 3.3.0p-1
+        if let Some((base, patch)) = version.rsplit_once('p') {
+            if patch
+                .trim_start_matches('-')
+                .chars()
+                .all(|c| c.is_ascii_digit())
+            {
+                version = base;
+            }
+        }
+
+        if version.is_empty() {
+            return Ok(None);
+        }
+
+        // Pre-releases use RubyGems syntax: 3.3.0.preview1 -> 3.3.0-preview1
+        return Ok(Some(
+            match version.find(|c: char| c.is_ascii_alphabetic()) {
+                Some(index) if version[..index].ends_with('.') => {
+                    format!("{}-{}", &version[..index - 1], &version[index..])
+                }
+                _ => version.to_owned(),
+            },
+        ));
+    }
+
+    Ok(None)
+}
+
+// BUNDLED WITH
+//    2.4.10
+pub fn parse_bundled_with(content: &str) -> Option<String> {
+    let mut lines = content.lines();
+
+    lines.find(|line| line.trim() == "BUNDLED WITH")?;
+
+    let version = lines.next()?.trim();
+
+    if version.is_empty() {
+        None
+    } else {
+        Some(version.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_engines() {
+        assert_eq!(strip_engine("3.2.2"), Ok("3.2.2"));
+        assert_eq!(strip_engine("ruby-3.2.2"), Ok("3.2.2"));
+        assert_eq!(strip_engine(" ruby-3.3.0-preview1 "), Ok("3.3.0-preview1"));
+        assert_eq!(strip_engine("jruby-9.4.0.0"), Err("jruby".into()));
+        assert_eq!(
+            strip_engine("truffleruby-23.1.0"),
+            Err("truffleruby".into())
+        );
+        assert_eq!(strip_engine("mruby"), Err("mruby".into()));
+    }
+
+    #[test]
+    fn parses_gemfile() {
+        assert_eq!(
+            parse_gemfile("source \"https://rubygems.org\"\n\nruby \"~> 3.2\"\n\ngem \"rails\""),
+            Ok(Some("^3.2".into()))
+        );
+        assert_eq!(parse_gemfile("ruby '3.2.2'"), Ok(Some("3.2.2".into())));
+        assert_eq!(
+            parse_gemfile("ruby \"~> 3.2.1\" # comment"),
+            Ok(Some("~3.2.1".into()))
+        );
+        assert_eq!(
+            parse_gemfile("ruby \">= 3.0\", \"< 3.4\""),
+            Ok(Some(">=3.0, <3.4".into()))
+        );
+        assert_eq!(
+            parse_gemfile("ruby \"3.2.2\", engine: \"ruby\", patchlevel: \"53\""),
+            Ok(Some("3.2.2".into()))
+        );
+        assert_eq!(parse_gemfile("gem \"rails\""), Ok(None));
+        assert_eq!(parse_gemfile("ruby file: \".ruby-version\""), Ok(None));
+    }
+
+    #[test]
+    fn errors_for_gemfile_engines() {
+        assert_eq!(
+            parse_gemfile("ruby \"3.1.0\", engine: \"jruby\", engine_version: \"9.4.0.0\""),
+            Err("jruby".into())
+        );
+    }
+
+    #[test]
+    fn parses_gemfile_lock() {
+        assert_eq!(
+            parse_gemfile_lock(
+                "GEM\n  remote: https://rubygems.org/\n\nRUBY VERSION\n   ruby 3.2.2p53\n\nBUNDLED WITH\n   2.4.10\n"
+            ),
+            Ok(Some("3.2.2".into()))
+        );
+        assert_eq!(
+            parse_gemfile_lock("RUBY VERSION\n   ruby 3.1.0p0 (jruby 9.4.0.0)\n"),
+            Err("jruby".into())
+        );
+        assert_eq!(
+            parse_gemfile_lock("RUBY VERSION\n   ruby 3.3.0.preview1\n"),
+            Ok(Some("3.3.0-preview1".into()))
+        );
+        assert_eq!(
+            parse_gemfile_lock("RUBY VERSION\n   ruby 3.4.0.rc1p-1\n"),
+            Ok(Some("3.4.0-rc1".into()))
+        );
+        assert_eq!(parse_gemfile_lock("BUNDLED WITH\n   2.4.10\n"), Ok(None));
+    }
+
+    #[test]
+    fn parses_bundled_with() {
+        assert_eq!(
+            parse_bundled_with("RUBY VERSION\n   ruby 3.2.2p53\n\nBUNDLED WITH\n   2.4.10\n"),
+            Some("2.4.10".into())
+        );
+        assert_eq!(parse_bundled_with("BUNDLED WITH\n"), None);
+        assert_eq!(parse_bundled_with("RUBY VERSION\n   ruby 3.2.2p53\n"), None);
+    }
+}
@@ -0,0 +1,267 @@
+use proto_pdk_test_utils::*;
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct RubyPluginConfig {
+    pub dist_url: String,
+}
+
+mod ruby_tool {
+    use super::*;
+
+    #[cfg(all(target_os = "linux", target_arch = "x86_64", target_env = "gnu"))]
+    generate_download_install_tests!("ruby-test", "3.4.4");
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_linux_arm64() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host_environment(HostEnvironment {
+                    arch: HostArch::Arm64,
+                    libc: HostLibc::Gnu,
+                    os: HostOS::Linux,
+                    ..Default::default()
+                });
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("3.3.7").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("portable-ruby/3.3.7".into()),
+                checksum_url: Some("https://api.github.com/repos/Homebrew/homebrew-portable-ruby/releases/tags/3.3.7".into()),
+                download_name: Some("portable-ruby-3.3.7.arm64_linux.bottle.tar.gz".into()),
+                download_url: "https://github.com/Homebrew/homebrew-portable-ruby/releases/download/3.3.7/portable-ruby-3.3.7.arm64_linux.bottle.tar.gz".into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_linux_x64() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host_environment(HostEnvironment {
+                    arch: HostArch::X64,
+                    libc: HostLibc::Gnu,
+                    os: HostOS::Linux,
+                    ..Default::default()
+                });
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("3.1.4").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("portable-ruby/3.1.4".into()),
+                checksum_url: Some("https://api.github.com/repos/Homebrew/homebrew-portable-ruby/releases/tags/3.1.4".into()),
+                download_name: Some("portable-ruby-3.1.4.x86_64_linux.bottle.tar.gz".into()),
+                download_url: "https://github.com/Homebrew/homebrew-portable-ruby/releases/download/3.1.4/portable-ruby-3.1.4.x86_64_linux.bottle.tar.gz".into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn doesnt_verify_checksum_from_mirrors() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host_environment(HostEnvironment {
+                    arch: HostArch::X64,
+                    libc: HostLibc::Gnu,
+                    os: HostOS::Linux,
+                    ..Default::default()
+                });
+                config.tool_config(RubyPluginConfig {
+                    dist_url: "https://mirror.example.com/portable-ruby/{version}/{file}".into(),
+                });
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("3.1.4").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("portable-ruby/3.1.4".into()),
+                checksum_url: None,
+                download_name: Some("portable-ruby-3.1.4.x86_64_linux.bottle.tar.gz".into()),
+                download_url: "https://mirror.example.com/portable-ruby/3.1.4/portable-ruby-3.1.4.x86_64_linux.bottle.tar.gz".into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "only available for glibc")]
+    async fn doesnt_support_linux_musl() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host_environment(HostEnvironment {
+                    arch: HostArch::X64,
+                    libc: HostLibc::Musl,
+                    os: HostOS::Linux,
+                    ..Default::default()
+                });
+            })
+            .await;
+
+        plugin
+            .download_prebuilt(DownloadPrebuiltInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("3.1.4").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(expected = "Unable to install Ruby, unsupported OS macos.")]
+    async fn doesnt_support_macos() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host(HostOS::MacOS, HostArch::Arm64);
+            })
+            .await;
+
+        plugin
+            .download_prebuilt(DownloadPrebuiltInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("3.1.4").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn verifies_checksum_from_release() {
+        let sandbox = create_empty_proto_sandbox();
+        sandbox.create_file("portable-ruby-3.4.4.x86_64_linux.bottle.tar.gz", "ruby");
+        sandbox.create_file(
+            "release.json",
+            r#"{"assets":[{"name":"portable-ruby-3.4.4.x86_64_linux.bottle.tar.gz","digest":"sha256:b9138194ffe9e7c8bb6d79d1ed56259553d18d9cb60b66e3ba5aa2e5b078055a"}]}"#,
+        );
+
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        let output = plugin
+            .verify_checksum(VerifyChecksumInput {
+                checksum_file: VirtualPath::OnlyReal(sandbox.path().join("release.json")),
+                download_file: VirtualPath::OnlyReal(
+                    sandbox
+                        .path()
+                        .join("portable-ruby-3.4.4.x86_64_linux.bottle.tar.gz"),
+                ),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(output.verified);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn fails_checksum_for_modified_download() {
+        let sandbox = create_empty_proto_sandbox();
+        sandbox.create_file("portable-ruby-3.4.4.x86_64_linux.bottle.tar.gz", "rubyx");
+        sandbox.create_file(
+            "release.json",
+            r#"{"assets":[{"name":"portable-ruby-3.4.4.x86_64_linux.bottle.tar.gz","digest":"sha256:b9138194ffe9e7c8bb6d79d1ed56259553d18d9cb60b66e3ba5aa2e5b078055a"}]}"#,
+        );
+
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        let output = plugin
+            .verify_checksum(VerifyChecksumInput {
+                checksum_file: VirtualPath::OnlyReal(sandbox.path().join("release.json")),
+                download_file: VirtualPath::OnlyReal(
+                    sandbox
+                        .path()
+                        .join("portable-ruby-3.4.4.x86_64_linux.bottle.tar.gz"),
+                ),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(!output.verified);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn skips_checksum_without_published_digest() {
+        let sandbox = create_empty_proto_sandbox();
+        sandbox.create_file("portable-ruby-3.1.4.x86_64_linux.bottle.tar.gz", "ruby");
+        sandbox.create_file(
+            "release.json",
+            r#"{"assets":[{"name":"portable-ruby-3.1.4.x86_64_linux.bottle.tar.gz","digest":null}]}"#,
+        );
+
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        let output = plugin
+            .verify_checksum(VerifyChecksumInput {
+                checksum_file: VirtualPath::OnlyReal(sandbox.path().join("release.json")),
+                download_file: VirtualPath::OnlyReal(
+                    sandbox
+                        .path()
+                        .join("portable-ruby-3.1.4.x86_64_linux.bottle.tar.gz"),
+                ),
+                ..Default::default()
+            })
+            .await;
+
+        assert!(output.verified);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn skips_native_install_without_prebuilts() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host(HostOS::MacOS, HostArch::Arm64);
+            })
+            .await;
+
+        let output = plugin
+            .native_install(NativeInstallInput {
+                context: ToolContext {
+                    version: VersionSpec::parse("3.4.4").unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await;
+
+        assert!(!output.installed);
+        assert!(output.skip_install);
+    }
+}
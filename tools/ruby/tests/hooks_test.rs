@@ -0,0 +1,121 @@
+use proto_pdk_test_utils::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct RubyPluginConfig {
+    pub per_version_gem_home: bool,
+}
+
+mod ruby_tool {
+    use super::*;
+
+    mod pre_run {
+        use super::*;
+
+        fn create_context() -> ToolContext {
+            ToolContext {
+                tool_dir: VirtualPath::WithReal {
+                    path: PathBuf::from("/proto/tools/ruby/3.2.2"),
+                    virtual_prefix: PathBuf::from("/proto"),
+                    real_prefix: PathBuf::from("/.proto"),
+                },
+                version: VersionSpec::parse("3.2.2").unwrap(),
+                ..ToolContext::default()
+            }
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn sets_gem_home_per_version() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("ruby-test", |config| {
+                    config.tool_config(RubyPluginConfig {
+                        per_version_gem_home: true,
+                    });
+                })
+                .await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+
+            let gem_home = PathBuf::from("/.proto/tools/ruby/3.2.2/gems")
+                .to_string_lossy()
+                .to_string();
+            let gem_path = format!(
+                "{gem_home}{}{}",
+                if cfg!(windows) { ";" } else { ":" },
+                PathBuf::from("/.proto/tools/ruby/3.2.2/lib/ruby/gems/3.2.0").to_string_lossy()
+            );
+
+            assert_eq!(
+                result.env,
+                Some(HashMap::from_iter([
+                    ("GEM_HOME".into(), gem_home),
+                    ("GEM_PATH".into(), gem_path),
+                ]))
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn does_nothing_by_default() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox.create_plugin("ruby-test").await;
+
+            let result = plugin
+                .pre_run(RunHook {
+                    context: create_context(),
+                    ..RunHook::default()
+                })
+                .await;
+
+            assert_eq!(result.args, None);
+            assert_eq!(result.env, None);
+        }
+    }
+
+    mod locate_executables {
+        use super::*;
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn includes_gem_bin_dirs_in_globals() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox.create_plugin("ruby-test").await;
+
+            let output = plugin
+                .locate_executables(LocateExecutablesInput::default())
+                .await;
+
+            assert_eq!(
+                output.globals_lookup_dirs,
+                vec!["$GEM_HOME/bin", "$TOOL_DIR/bin"]
+            );
+        }
+
+        #[tokio::test(flavor = "multi_thread")]
+        async fn includes_per_version_gem_bin_dir_in_globals() {
+            let sandbox = create_empty_proto_sandbox();
+            let plugin = sandbox
+                .create_plugin_with_config("ruby-test", |config| {
+                    config.tool_config(RubyPluginConfig {
+                        per_version_gem_home: true,
+                    });
+                })
+                .await;
+
+            let output = plugin
+                .locate_executables(LocateExecutablesInput::default())
+                .await;
+
+            assert_eq!(
+                output.globals_lookup_dirs,
+                vec!["$GEM_HOME/bin", "$TOOL_DIR/gems/bin", "$TOOL_DIR/bin"]
+            );
+        }
+    }
+}
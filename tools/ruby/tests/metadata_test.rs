@@ -0,0 +1,81 @@
+use proto_pdk_test_utils::*;
+
+mod ruby_tool {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn registers_metadata() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        let metadata = plugin.register_tool(ToolMetadataInput::default()).await;
+
+        assert_eq!(metadata.name, "Ruby");
+        assert!(metadata.config_schema.is_some());
+        assert_eq!(
+            metadata.plugin_version.unwrap().to_string(),
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn downloads_prebuilt_on_linux() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host_environment(HostEnvironment {
+                    arch: HostArch::X64,
+                    libc: HostLibc::Gnu,
+                    os: HostOS::Linux,
+                    ..Default::default()
+                });
+            })
+            .await;
+
+        let metadata = plugin.register_tool(ToolMetadataInput::default()).await;
+
+        assert_eq!(
+            metadata.default_install_strategy,
+            InstallStrategy::DownloadPrebuilt
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn builds_from_source_on_linux_musl() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host_environment(HostEnvironment {
+                    arch: HostArch::X64,
+                    libc: HostLibc::Musl,
+                    os: HostOS::Linux,
+                    ..Default::default()
+                });
+            })
+            .await;
+
+        let metadata = plugin.register_tool(ToolMetadataInput::default()).await;
+
+        assert_eq!(
+            metadata.default_install_strategy,
+            InstallStrategy::BuildFromSource
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn builds_from_source_on_macos() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("ruby-test", |config| {
+                config.host(HostOS::MacOS, HostArch::Arm64);
+            })
+            .await;
+
+        let metadata = plugin.register_tool(ToolMetadataInput::default()).await;
+
+        assert_eq!(
+            metadata.default_install_strategy,
+            InstallStrategy::BuildFromSource
+        );
+    }
+}
@@ -0,0 +1,142 @@
+use proto_pdk_test_utils::*;
+
+mod ruby_tool {
+    use super::*;
+
+    generate_resolve_versions_tests!("ruby-test", {
+        "3.1.0" => "3.1.0",
+        "3.2.2" => "3.2.2",
+    });
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn loads_versions_from_git() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        let output = plugin.load_versions(LoadVersionsInput::default()).await;
+
+        assert!(!output.versions.is_empty());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_ruby_version() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "ruby-3.2.2\n".into(),
+                    file: ".ruby-version".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.2.2").unwrap()),
+            }
+        );
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "nodejs 20.10.0\nruby 3.3.0\n".into(),
+                    file: ".tool-versions".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.3.0").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(
+        expected = "is not supported, only the default engine (CRuby) can be installed."
+    )]
+    async fn errors_for_other_engines() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        plugin
+            .parse_version_file(ParseVersionFileInput {
+                content: "jruby-9.4.0.0".into(),
+                file: ".ruby-version".into(),
+                ..Default::default()
+            })
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn skips_unusable_tool_versions() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "ruby jruby-9.4.0.0 ruby-3.2.2 3.3.0\n".into(),
+                    file: ".tool-versions".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.2.2").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[should_panic(
+        expected = "is not supported, only the default engine (CRuby) can be installed."
+    )]
+    async fn errors_for_tool_versions_with_only_other_engines() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        plugin
+            .parse_version_file(ParseVersionFileInput {
+                content: "ruby truffleruby-24.1.1 jruby-9.4.0.0\n".into(),
+                file: ".tool-versions".into(),
+                ..Default::default()
+            })
+            .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_gemfile() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "source \"https://rubygems.org\"\n\nruby \"~> 3.2\"\n\ngem \"rails\", \"~> 7.1\"\n".into(),
+                    file: "Gemfile".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("^3.2").unwrap()),
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn parses_gemfile_lock() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox.create_plugin("ruby-test").await;
+
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "GEM\n  remote: https://rubygems.org/\n  specs:\n\nRUBY VERSION\n   ruby 3.2.2p53\n\nBUNDLED WITH\n   2.4.10\n".into(),
+                    file: "Gemfile.lock".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("3.2.2").unwrap()),
+            }
+        );
+    }
+}
@@ -0,0 +1,15 @@
+#[derive(Debug, schematic::Schematic, serde::Deserialize, serde::Serialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RustPluginConfig {
+    pub rustup: bool,
+    pub targets: Vec<String>,
+}
+
+impl Default for RustPluginConfig {
+    fn default() -> Self {
+        Self {
+            rustup: true,
+            targets: vec![],
+        }
+    }
+}
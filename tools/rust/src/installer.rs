@@ -0,0 +1,107 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+fn move_path(source: &Path, dest: &Path) -> io::Result<()> {
+    // Merge directories that already exist in the destination,
+    // as multiple components may write to the same folder
+    if source.is_dir() && dest.exists() {
+        for entry in fs::read_dir(source)? {
+            let entry = entry?;
+
+            move_path(&entry.path(), &dest.join(entry.file_name()))?;
+        }
+
+        fs::remove_dir_all(source)?;
+
+        return Ok(());
+    }
+
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::rename(source, dest)?;
+
+    Ok(())
+}
+
+// This replicates the `install.sh` script that is bundled in the dist
+// archives, by moving each component's files into the root directory.
+// https://github.com/rust-lang/rust-installer/blob/master/install-template.sh
+pub fn install_components(tool_dir: &Path) -> io::Result<()> {
+    let components_file = tool_dir.join("components");
+
+    // Already installed, or not a dist archive
+    if !components_file.exists() {
+        return Ok(());
+    }
+
+    for component in fs::read_to_string(&components_file)?.lines() {
+        let component = component.trim();
+
+        if component.is_empty() {
+            continue;
+        }
+
+        let component_dir = tool_dir.join(component);
+        let manifest = fs::read_to_string(component_dir.join("manifest.in"))?;
+
+        for line in manifest.lines() {
+            // file:bin/rustc, dir:share/doc/rust
+            let Some((_, rel_path)) = line.trim().split_once(':') else {
+                continue;
+            };
+
+            move_path(&component_dir.join(rel_path), &tool_dir.join(rel_path))?;
+        }
+
+        fs::remove_dir_all(component_dir)?;
+    }
+
+    for file in ["components", "install.sh", "rust-installer-version"] {
+        let path = tool_dir.join(file);
+
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starbase_sandbox::create_sandbox;
+
+    #[test]
+    fn moves_component_files_into_root() {
+        let sandbox = create_sandbox("dist");
+        let root = sandbox.path();
+
+        install_components(root).unwrap();
+
+        assert!(root.join("bin/cargo").exists());
+        assert!(root.join("bin/rustc").exists());
+        assert!(root.join("share/doc/cargo/README.md").exists());
+        assert!(root.join("lib/rustlib/etc").is_dir());
+        assert!(root.join("lib/rustlib/x86_64-unknown-linux-gnu").is_dir());
+
+        assert!(!root.join("cargo").exists());
+        assert!(!root.join("rustc").exists());
+        assert!(!root.join("components").exists());
+        assert!(!root.join("install.sh").exists());
+    }
+
+    #[test]
+    fn skips_already_installed_dirs() {
+        let sandbox = create_sandbox("dist");
+        let root = sandbox.path();
+
+        install_components(root).unwrap();
+        install_components(root).unwrap();
+
+        assert!(root.join("bin/cargo").exists());
+    }
+}
@@ -1,7 +1,10 @@
+mod config;
 #[cfg(feature = "wasm")]
 mod helpers;
+mod installer;
 #[cfg(feature = "wasm")]
 mod proto;
+mod targets;
 mod toolchain_toml;
 
 #[cfg(feature = "wasm")]
@@ -1,10 +1,15 @@
+use crate::config::RustPluginConfig;
 use crate::helpers::*;
+use crate::installer::install_components;
+use crate::targets::*;
 use crate::toolchain_toml::ToolchainToml;
 use extism_pdk::*;
 use proto_pdk::*;
+use schematic::SchemaBuilder;
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use tool_common::find_tool_version;
 
 #[host_fn]
 extern "ExtismHost" {
@@ -21,14 +26,22 @@ fn get_toolchain_dir(env: &HostEnvironment) -> AnyResult<VirtualPath> {
 #[plugin_fn]
 pub fn register_tool(Json(_): Json<ToolMetadataInput>) -> FnResult<Json<ToolMetadataOutput>> {
     let env = get_host_environment()?;
+    let config = get_tool_config::<RustPluginConfig>()?;
 
     Ok(Json(ToolMetadataOutput {
         name: NAME.into(),
         type_of: PluginType::Language,
+        config_schema: Some(SchemaBuilder::build_root::<RustPluginConfig>()),
         default_version: Some(UnresolvedVersionSpec::Alias("stable".into())),
-        inventory: ToolInventoryMetadata {
-            override_dir: Some(get_toolchain_dir(&env)?),
-            version_suffix: Some(format!("-{}", get_target_triple(&env, NAME)?)),
+        // Toolchains are managed by rustup, so point to its directory,
+        // otherwise proto manages the inventory like any other tool
+        inventory: if config.rustup {
+            ToolInventoryMetadata {
+                override_dir: Some(get_toolchain_dir(&env)?),
+                version_suffix: Some(format!("-{}", get_target_triple(&env, NAME)?)),
+            }
+        } else {
+            ToolInventoryMetadata::default()
         },
         minimum_proto_version: Some(Version::new(0, 42, 0)),
         plugin_version: Version::parse(env!("CARGO_PKG_VERSION")).ok(),
@@ -59,6 +72,16 @@ pub fn resolve_version(
 ) -> FnResult<Json<ResolveVersionOutput>> {
     let mut output = ResolveVersionOutput::default();
 
+    // The dist server has no archive for the stable channel itself,
+    // so resolve it to the latest release when not using rustup
+    if let UnresolvedVersionSpec::Alias(alias) = &input.initial {
+        if alias == "stable" && !get_tool_config::<RustPluginConfig>()?.rustup {
+            output.candidate = Some(UnresolvedVersionSpec::Alias("latest".into()));
+
+            return Ok(Json(output));
+        }
+    }
+
     // Allow channels as explicit aliases
     if is_non_version_channel(&input.initial) {
         output.version = VersionSpec::parse(input.initial.to_string()).ok();
@@ -72,7 +95,11 @@ pub fn resolve_version(
 #[plugin_fn]
 pub fn detect_version_files(_: ()) -> FnResult<Json<DetectVersionOutput>> {
     Ok(Json(DetectVersionOutput {
-        files: vec!["rust-toolchain.toml".into(), "rust-toolchain".into()],
+        files: vec![
+            "rust-toolchain.toml".into(),
+            "rust-toolchain".into(),
+            ".tool-versions".into(),
+        ],
         ignore: vec![],
     }))
 }
@@ -93,6 +120,8 @@ pub fn parse_version_file(
         if let Some(channel) = config.toolchain.channel {
             output.version = Some(UnresolvedVersionSpec::parse(channel)?);
         }
+    } else if input.file == ".tool-versions" {
+        output.version = find_tool_version(&input.content, "rust");
     }
 
     Ok(Json(output))
@@ -103,6 +132,15 @@ pub fn native_install(
     Json(input): Json<NativeInstallInput>,
 ) -> FnResult<Json<NativeInstallOutput>> {
     let env = get_host_environment()?;
+    let config = get_tool_config::<RustPluginConfig>()?;
+
+    // Fallback to downloading a pre-built archive
+    if !config.rustup {
+        return Ok(Json(NativeInstallOutput {
+            skip_install: true,
+            ..NativeInstallOutput::default()
+        }));
+    }
 
     // Install rustup if it does not exist
     if !command_exists(&env, "rustup") {
@@ -183,6 +221,20 @@ pub fn native_install(
         );
     }
 
+    // Add configured cross-compilation targets, which is a no-op
+    // for targets that have already been added to the toolchain
+    if !config.targets.is_empty() {
+        debug!(
+            "Adding targets <id>{}</id> to toolchain",
+            config.targets.join(", ")
+        );
+
+        let mut args = vec!["target", "add", "--toolchain", triple.as_str()];
+        args.extend(config.targets.iter().map(|target| target.as_str()));
+
+        exec_command!(inherit, "rustup", args);
+    }
+
     // Always mark as installed so that binaries can be located!
     Ok(Json(NativeInstallOutput {
         installed: true,
@@ -195,9 +247,28 @@ pub fn native_uninstall(
     Json(input): Json<NativeUninstallInput>,
 ) -> FnResult<Json<NativeUninstallOutput>> {
     let env = get_host_environment()?;
+    let config = get_tool_config::<RustPluginConfig>()?;
+
+    // Let proto remove the tool directory
+    if !config.rustup {
+        return Ok(Json(NativeUninstallOutput {
+            skip_uninstall: true,
+            ..NativeUninstallOutput::default()
+        }));
+    }
+
     let channel = get_channel_from_version(&input.context.version);
     let triple = format!("{}-{}", channel, get_target_triple(&env, NAME)?);
 
+    // Remove configured targets first, so that rustup cleans up their
+    // components, before the toolchain itself is uninstalled
+    if !config.targets.is_empty() && input.context.tool_dir.exists() {
+        let mut args = vec!["target", "remove", "--toolchain", triple.as_str()];
+        args.extend(config.targets.iter().map(|target| target.as_str()));
+
+        exec_command!(inherit, "rustup", args);
+    }
+
     exec_command!(inherit, "rustup", ["toolchain", "uninstall", &triple]);
 
     Ok(Json(NativeUninstallOutput {
@@ -206,11 +277,85 @@ pub fn native_uninstall(
     }))
 }
 
+#[plugin_fn]
+pub fn download_prebuilt(
+    Json(input): Json<DownloadPrebuiltInput>,
+) -> FnResult<Json<DownloadPrebuiltOutput>> {
+    let env = get_host_environment()?;
+    let triple = get_target_triple(&env, NAME)?;
+    let channel = get_channel_from_version(&input.context.version);
+
+    // Dated nightlies are stored in a sub-directory: nightly-2023-07-03
+    let (base_url, channel) = match channel.strip_prefix("nightly-") {
+        Some(date) => (
+            format!("https://static.rust-lang.org/dist/{date}"),
+            "nightly".to_owned(),
+        ),
+        None => ("https://static.rust-lang.org/dist".to_owned(), channel),
+    };
+
+    let prefix = format!("rust-{channel}-{triple}");
+    let filename = format!("{prefix}.tar.xz");
+
+    Ok(Json(DownloadPrebuiltOutput {
+        archive_prefix: Some(prefix),
+        checksum_url: Some(format!("{base_url}/{filename}.sha256")),
+        download_url: format!("{base_url}/{filename}"),
+        download_name: Some(filename),
+        ..DownloadPrebuiltOutput::default()
+    }))
+}
+
+#[plugin_fn]
+pub fn post_install(Json(input): Json<InstallHook>) -> FnResult<()> {
+    let config = get_tool_config::<RustPluginConfig>()?;
+
+    if config.rustup {
+        return Ok(());
+    }
+
+    debug!("Installing Rust components into the tool directory");
+
+    install_components(input.context.tool_dir.any_path())?;
+
+    Ok(())
+}
+
 #[plugin_fn]
 pub fn locate_executables(
     Json(_): Json<LocateExecutablesInput>,
 ) -> FnResult<Json<LocateExecutablesOutput>> {
     let env = get_host_environment()?;
+    let config = get_tool_config::<RustPluginConfig>()?;
+
+    // Binaries are located directly in the tool directory,
+    // so we can create shims and bins like other tools
+    if !config.rustup {
+        return Ok(Json(LocateExecutablesOutput {
+            exes: HashMap::from_iter([
+                (
+                    "cargo".into(),
+                    ExecutableConfig::new_primary(env.os.get_exe_name("bin/cargo")),
+                ),
+                (
+                    "rustc".into(),
+                    ExecutableConfig::new(env.os.get_exe_name("bin/rustc")),
+                ),
+                (
+                    "rustdoc".into(),
+                    ExecutableConfig::new(env.os.get_exe_name("bin/rustdoc")),
+                ),
+            ]),
+            exes_dir: Some("bin".into()),
+            globals_lookup_dirs: vec![
+                "$CARGO_INSTALL_ROOT/bin".into(),
+                "$CARGO_HOME/bin".into(),
+                "$HOME/.cargo/bin".into(),
+            ],
+            globals_prefix: Some("cargo-".into()),
+            ..LocateExecutablesOutput::default()
+        }));
+    }
 
     // Binaries are provided by Cargo (`~/.cargo/bin`), so don't create
     // our own shim and bin. But we do need to ensure that the install
@@ -235,10 +380,19 @@ pub fn locate_executables(
 #[plugin_fn]
 pub fn sync_manifest(Json(_): Json<SyncManifestInput>) -> FnResult<Json<SyncManifestOutput>> {
     let env = get_host_environment()?;
+    let mut output = SyncManifestOutput::default();
+
+    // Installed versions are already tracked by proto
+    if !get_tool_config::<RustPluginConfig>()?.rustup {
+        output.skip_sync = true;
+
+        return Ok(Json(output));
+    }
+
     let triple = get_target_triple(&env, NAME)?;
     let toolchain_dir = get_toolchain_dir(&env)?;
-    let mut output = SyncManifestOutput::default();
     let mut versions = vec![];
+    let mut targets = vec![];
 
     // Path may not be whitelisted, so exit early instead of failing
     let Ok(dirs) = fs::read_dir(toolchain_dir) else {
@@ -266,9 +420,21 @@ pub fn sync_manifest(Json(_): Json<SyncManifestInput>) -> FnResult<Json<SyncMani
             continue;
         }
 
+        // The host target is always installed, so only report additional targets
+        targets.push((
+            spec.to_string(),
+            get_installed_targets(&dir)
+                .into_iter()
+                .filter(|target| *target != triple)
+                .collect(),
+        ));
         versions.push(spec);
     }
 
+    if let Some(report) = format_installed_targets(&targets) {
+        debug!("{report}");
+    }
+
     if !versions.is_empty() {
         output.versions = Some(versions);
     }
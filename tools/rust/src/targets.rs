@@ -0,0 +1,105 @@
+use std::fs;
+use std::path::Path;
+
+pub fn get_installed_targets(toolchain_dir: &Path) -> Vec<String> {
+    let mut targets = vec![];
+
+    // Each installed target has its own folder within `lib/rustlib`,
+    // alongside non-target folders like `etc` and `src`
+    let Ok(dirs) = fs::read_dir(toolchain_dir.join("lib/rustlib")) else {
+        return targets;
+    };
+
+    for dir in dirs.flatten() {
+        let path = dir.path();
+
+        if !path.is_dir() {
+            continue;
+        }
+
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+        if name.contains('-') {
+            targets.push(name.to_string());
+        }
+    }
+
+    targets.sort();
+    targets
+}
+
+/// Format the installed targets of each toolchain into a report
+/// that can be logged to the terminal, one toolchain per line.
+pub fn format_installed_targets(toolchains: &[(String, Vec<String>)]) -> Option<String> {
+    let lines = toolchains
+        .iter()
+        .filter(|(_, targets)| !targets.is_empty())
+        .map(|(version, targets)| {
+            format!(
+                "  <hash>{version}</hash> - {}",
+                targets
+                    .iter()
+                    .map(|target| format!("<id>{target}</id>"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        return None;
+    }
+
+    Some(format!("Installed Rust targets:\n{}", lines.join("\n")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[test]
+    fn finds_target_dirs() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("lib/rustlib/x86_64-unknown-linux-gnu/lib/.keep", "");
+        sandbox.create_file("lib/rustlib/wasm32-unknown-unknown/lib/.keep", "");
+        sandbox.create_file("lib/rustlib/etc/.keep", "");
+        sandbox.create_file("lib/rustlib/components", "rustc");
+
+        assert_eq!(
+            get_installed_targets(sandbox.path()),
+            vec!["wasm32-unknown-unknown", "x86_64-unknown-linux-gnu"]
+        );
+    }
+
+    #[test]
+    fn returns_nothing_for_missing_rustlib() {
+        let sandbox = create_empty_sandbox();
+
+        assert!(get_installed_targets(sandbox.path()).is_empty());
+    }
+
+    #[test]
+    fn formats_targets_per_toolchain() {
+        assert_eq!(
+            format_installed_targets(&[
+                (
+                    "1.80.0".into(),
+                    vec![
+                        "thumbv7em-none-eabihf".into(),
+                        "wasm32-unknown-unknown".into()
+                    ]
+                ),
+                ("1.79.0".into(), vec![]),
+                ("1.78.0".into(), vec!["x86_64-unknown-linux-gnu".into()]),
+            ])
+            .unwrap(),
+            "Installed Rust targets:\n  <hash>1.80.0</hash> - <id>thumbv7em-none-eabihf</id>, <id>wasm32-unknown-unknown</id>\n  <hash>1.78.0</hash> - <id>x86_64-unknown-linux-gnu</id>"
+        );
+    }
+
+    #[test]
+    fn skips_report_without_targets() {
+        assert_eq!(format_installed_targets(&[("1.80.0".into(), vec![])]), None);
+    }
+}
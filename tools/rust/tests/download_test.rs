@@ -3,9 +3,155 @@ use proto_pdk_test_utils::*;
 // We use a fake home directory but rustup requires a real one!
 // generate_download_install_tests!("rust-test", "1.70.0");
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RustPluginConfig {
+    pub rustup: bool,
+}
+
 mod rust_tool {
     use super::*;
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_linux_x64_without_rustup() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("rust-test", |config| {
+                config.host(HostOS::Linux, HostArch::X64);
+                config.tool_config(RustPluginConfig { rustup: false });
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("1.70.0").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("rust-1.70.0-x86_64-unknown-linux-gnu".into()),
+                checksum_url: Some(
+                    "https://static.rust-lang.org/dist/rust-1.70.0-x86_64-unknown-linux-gnu.tar.xz.sha256"
+                        .into()
+                ),
+                download_name: Some("rust-1.70.0-x86_64-unknown-linux-gnu.tar.xz".into()),
+                download_url:
+                    "https://static.rust-lang.org/dist/rust-1.70.0-x86_64-unknown-linux-gnu.tar.xz"
+                        .into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_macos_arm64_without_rustup() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("rust-test", |config| {
+                config.host(HostOS::MacOS, HostArch::Arm64);
+                config.tool_config(RustPluginConfig { rustup: false });
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("1.70.0").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("rust-1.70.0-aarch64-apple-darwin".into()),
+                checksum_url: Some(
+                    "https://static.rust-lang.org/dist/rust-1.70.0-aarch64-apple-darwin.tar.xz.sha256"
+                        .into()
+                ),
+                download_name: Some("rust-1.70.0-aarch64-apple-darwin.tar.xz".into()),
+                download_url:
+                    "https://static.rust-lang.org/dist/rust-1.70.0-aarch64-apple-darwin.tar.xz"
+                        .into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_windows_x64_without_rustup() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("rust-test", |config| {
+                config.host(HostOS::Windows, HostArch::X64);
+                config.tool_config(RustPluginConfig { rustup: false });
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("1.70.0").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("rust-1.70.0-x86_64-pc-windows-msvc".into()),
+                checksum_url: Some(
+                    "https://static.rust-lang.org/dist/rust-1.70.0-x86_64-pc-windows-msvc.tar.xz.sha256"
+                        .into()
+                ),
+                download_name: Some("rust-1.70.0-x86_64-pc-windows-msvc.tar.xz".into()),
+                download_url:
+                    "https://static.rust-lang.org/dist/rust-1.70.0-x86_64-pc-windows-msvc.tar.xz"
+                        .into(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn supports_dated_nightly_without_rustup() {
+        let sandbox = create_empty_proto_sandbox();
+        let plugin = sandbox
+            .create_plugin_with_config("rust-test", |config| {
+                config.host(HostOS::Linux, HostArch::Arm64);
+                config.tool_config(RustPluginConfig { rustup: false });
+            })
+            .await;
+
+        assert_eq!(
+            plugin
+                .download_prebuilt(DownloadPrebuiltInput {
+                    context: ToolContext {
+                        version: VersionSpec::parse("nightly-2023-07-03").unwrap(),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                })
+                .await,
+            DownloadPrebuiltOutput {
+                archive_prefix: Some("rust-nightly-aarch64-unknown-linux-gnu".into()),
+                checksum_url: Some(
+                    "https://static.rust-lang.org/dist/2023-07-03/rust-nightly-aarch64-unknown-linux-gnu.tar.xz.sha256"
+                        .into()
+                ),
+                download_name: Some("rust-nightly-aarch64-unknown-linux-gnu.tar.xz".into()),
+                download_url:
+                    "https://static.rust-lang.org/dist/2023-07-03/rust-nightly-aarch64-unknown-linux-gnu.tar.xz"
+                        .into(),
+                ..Default::default()
+            }
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn locates_linux_bin() {
         let sandbox = create_empty_proto_sandbox();
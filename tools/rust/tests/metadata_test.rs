@@ -21,5 +21,6 @@ mod rust_tool {
         );
         assert!(metadata.inventory.override_dir.is_some());
         assert!(metadata.inventory.version_suffix.is_some());
+        assert!(metadata.config_schema.is_some());
     }
 }
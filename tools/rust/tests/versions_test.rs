@@ -50,6 +50,18 @@ mod rust_tool {
                 version: Some(UnresolvedVersionSpec::parse("1.60.0").unwrap()),
             }
         );
+        assert_eq!(
+            plugin
+                .parse_version_file(ParseVersionFileInput {
+                    content: "rust ref:1a2b3c 1.75.0\n".into(),
+                    file: ".tool-versions".into(),
+                    ..Default::default()
+                })
+                .await,
+            ParseVersionFileOutput {
+                version: Some(UnresolvedVersionSpec::parse("1.75.0").unwrap()),
+            }
+        );
     }
 
     #[tokio::test(flavor = "multi_thread")]